@@ -0,0 +1,103 @@
+use std::ops::{Add, AddAssign, Sub};
+
+// A 2D position, with y increasing downwards (i.e. the row number when reading a grid as text)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Coord {
+    pub const fn new(x: isize, y: isize) -> Coord {
+        Coord { x, y }
+    }
+
+    pub fn manhattan_dist(&self, other: &Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // The four orthogonally adjacent coords, in the order north, east, south, west
+    pub fn neighbours(&self) -> impl Iterator<Item = Coord> {
+        let c = *self;
+        [
+            Coord::new(c.x, c.y - 1),
+            Coord::new(c.x + 1, c.y),
+            Coord::new(c.x, c.y + 1),
+            Coord::new(c.x - 1, c.y),
+        ].into_iter()
+    }
+
+    // All eight adjacent coords, including diagonals, in row order
+    pub fn all_neighbours(&self) -> impl Iterator<Item = Coord> {
+        let c = *self;
+        (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| Coord::new(c.x + dx, c.y + dy)))
+            .filter(move |n| n != &c)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Coord {
+        Coord::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Coord {
+        Coord::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Coord3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Coord3 {
+        Coord3 { x, y, z }
+    }
+
+    pub fn manhattan_dist(&self, other: &Coord3) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    // The six coords sharing a face with this one
+    pub fn neighbours(&self) -> impl Iterator<Item = Coord3> {
+        let c = *self;
+        [
+            Coord3::new(c.x - 1, c.y, c.z), Coord3::new(c.x + 1, c.y, c.z),
+            Coord3::new(c.x, c.y - 1, c.z), Coord3::new(c.x, c.y + 1, c.z),
+            Coord3::new(c.x, c.y, c.z - 1), Coord3::new(c.x, c.y, c.z + 1),
+        ].into_iter()
+    }
+}
+
+impl Add for Coord3 {
+    type Output = Coord3;
+
+    fn add(self, rhs: Coord3) -> Coord3 {
+        Coord3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, rhs: Coord3) -> Coord3 {
+        Coord3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
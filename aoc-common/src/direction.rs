@@ -0,0 +1,55 @@
+use crate::Coord;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction { North, East, South, West }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rotation { Right, Left, Half, Nothing }
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn turn_right(&self) -> Direction {
+        use Direction::*;
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        use Direction::*;
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn(&self, rotation: &Rotation) -> Direction {
+        match rotation {
+            Rotation::Right => self.turn_right(),
+            Rotation::Left => self.turn_left(),
+            Rotation::Half => self.opposite(),
+            Rotation::Nothing => *self,
+        }
+    }
+
+    // The offset of a single step in this direction, with north being up (towards lower y)
+    pub fn delta(&self) -> Coord {
+        use Direction::*;
+        match self {
+            North => Coord::new(0, -1),
+            East => Coord::new(1, 0),
+            South => Coord::new(0, 1),
+            West => Coord::new(-1, 0),
+        }
+    }
+}
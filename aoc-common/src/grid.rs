@@ -0,0 +1,176 @@
+use std::ops::{Index, IndexMut};
+use crate::parse::ParseError;
+use crate::Coord;
+
+// A dense, rectangular grid of cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    // Builds a grid from lines of text, converting each character into a cell. Every line must be
+    // the same length.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> T) -> Grid<T> {
+        Grid::try_parse(input, |c| Ok::<T, String>(parse_cell(c))).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // As parse, but parse_cell can reject a character by returning a description of what was
+    // expected instead
+    pub fn try_parse<E: Into<String>>(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.lines() {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = parse_cell(c)
+                    .map_err(|expected| ParseError::at(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+            let row_width = cells.len() - row_start;
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                let end = line.char_indices().nth(width).map_or(line.len(), |(i, _)| i);
+                return Err(ParseError::at(input, &line[end..], format!("a row of width {}", width)));
+            }
+            height += 1;
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: &Coord) -> bool {
+        coord.x >= 0 && (coord.x as usize) < self.width && coord.y >= 0 && (coord.y as usize) < self.height
+    }
+
+    pub fn get(&self, coord: &Coord) -> Option<&T> {
+        if self.contains(coord) {
+            Some(&self.cells[self.index_of(coord.x as usize, coord.y as usize)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coord: &Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            let index = self.index_of(coord.x as usize, coord.y as usize);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    // Every coord in the grid, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord::new(x as isize, y as isize)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // The orthogonally adjacent coords that lie within the grid
+    pub fn neighbours(&self, coord: &Coord) -> impl Iterator<Item = Coord> + '_ {
+        coord.neighbours().filter(|n| self.contains(n))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(coord, _)| coord)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        y * self.width + x
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        &self.cells[self.index_of(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let index = self.index_of(x, y);
+        &mut self.cells[index]
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(&coord).unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", coord, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(&coord).unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", coord, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Coord, Grid};
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse("123\n456\n", |c| c.to_digit(10).unwrap());
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[Coord::new(0, 1)], 4);
+        assert_eq!(grid.get(&Coord::new(3, 0)), None);
+        assert_eq!(grid.position(|&d| d == 5), Some(Coord::new(1, 1)));
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(grid.neighbours(&Coord::new(0, 0)).collect::<Vec<_>>(), vec![Coord::new(1, 0), Coord::new(0, 1)]);
+        assert_eq!(grid.neighbours(&Coord::new(1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn ragged_rows_rejected() {
+        let error = Grid::try_parse("12\n3\n", Ok::<char, String>).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a row of width 2");
+    }
+}
@@ -0,0 +1,66 @@
+use std::env::args;
+use std::fs::{read_to_string, File};
+use std::io::{self, stdin, BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::exit;
+
+// Reads puzzle input from the given file, or from stdin if the path is "-"
+pub fn read_input_file(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        read_to_string(path)
+    }
+}
+
+// Reads puzzle input from the path given as the first command line argument (or stdin, for "-"),
+// falling back to default_path if there are no arguments. Exits if the input can't be read.
+pub fn read_input_from_args(default_path: &str) -> String {
+    let path = args().nth(1).unwrap_or_else(|| default_path.to_string());
+    read_input_file(&path).unwrap_or_else(|e| {
+        eprintln!("Could not read input file {}: {}", path, e);
+        exit(1);
+    })
+}
+
+// Reads puzzle input as per read_input_from_args, defaulting to the input.txt file in the calling
+// crate's directory
+#[macro_export]
+macro_rules! read_input {
+    () => {
+        $crate::input::read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))
+    };
+}
+
+// Opens puzzle input for streaming rather than reading it all at once, from the given file or from
+// stdin if the path is "-"
+pub fn open_input_file(path: impl AsRef<Path>) -> io::Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        Ok(Box::new(stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+// Opens puzzle input as per open_input_file, from the path given as the first command line argument
+// or default_path. Exits if the input can't be opened.
+pub fn open_input_from_args(default_path: &str) -> Box<dyn BufRead> {
+    let path = args().nth(1).unwrap_or_else(|| default_path.to_string());
+    open_input_file(&path).unwrap_or_else(|e| {
+        eprintln!("Could not open input file {}: {}", path, e);
+        exit(1);
+    })
+}
+
+// Opens puzzle input as per open_input_from_args, defaulting to the input.txt file in the calling
+// crate's directory
+#[macro_export]
+macro_rules! open_input {
+    () => {
+        $crate::input::open_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))
+    };
+}
@@ -0,0 +1,11 @@
+// Types shared between the days' solutions
+pub mod coord;
+pub mod direction;
+pub mod grid;
+pub mod input;
+pub mod parse;
+
+pub use coord::{Coord, Coord3};
+pub use direction::{Direction, Rotation};
+pub use grid::Grid;
+pub use parse::ParseError;
@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::process::exit;
+use std::str::FromStr;
+
+// A problem found while parsing puzzle input, located by (1-based) line and column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    source_line: String,
+    width: usize,
+}
+
+impl ParseError {
+    // An error at token, which must be a slice of text
+    pub fn at(text: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at_offset(text, offset_of(text, token), token.len(), expected)
+    }
+
+    // An error at the len bytes starting at byte offset within text
+    pub fn at_offset(text: &str, offset: usize, len: usize, expected: impl Into<String>) -> ParseError {
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+        let source_line = text[line_start..line_end].trim_end_matches('\r');
+
+        // A line break isn't visible in the snippet, so is reported as the end of the line instead
+        let token = text[offset..offset + len].trim_end_matches(['\r', '\n']);
+        let found = if !token.is_empty() {
+            format!("{:?}", token)
+        } else {
+            match text[offset..].chars().next() {
+                None => "end of input".to_string(),
+                Some('\n') | Some('\r') => "end of line".to_string(),
+                Some(c) => format!("{:?}", c),
+            }
+        };
+
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found,
+            source_line: source_line.to_string(),
+            width: token.chars().count().max(1),
+        }
+    }
+
+    // Re-locates an error found while parsing inner, a slice of outer, to its position within outer
+    pub fn within(self, outer: &str, inner: &str) -> ParseError {
+        let inner_start = ParseError::at_offset(outer, offset_of(outer, inner), 0, "");
+        let line = inner_start.line + self.line - 1;
+        let column = if self.line == 1 { inner_start.column + self.column - 1 } else { self.column };
+        let source_line = outer.lines().nth(line - 1).unwrap_or("").to_string();
+        ParseError { line, column, source_line, ..self }
+    }
+
+    pub fn in_file(self, file: impl Into<PathBuf>) -> ParseError {
+        ParseError { file: Some(file.into()), ..self }
+    }
+
+    // For the days' own binaries, which have no better way of reporting a bad input
+    pub fn exit(&self) -> ! {
+        eprintln!("Error: {}", self);
+        exit(1);
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let file = self.file.as_ref().map_or("<input>".to_string(), |p| p.display().to_string());
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        writeln!(f, "expected {}, found {}", self.expected, self.found)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_number, self.source_line)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(self.width))
+    }
+}
+
+impl Error for ParseError {}
+
+// The byte offset of slice within text. Anything that isn't a slice of text is treated as being at
+// its end, so that errors are still reported (if imprecisely) rather than panicking.
+fn offset_of(text: &str, slice: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let pos = slice.as_ptr() as usize;
+    if pos >= start && pos + slice.len() <= start + text.len() {
+        pos - start
+    } else {
+        text.len()
+    }
+}
+
+// An empty slice at the end of s, for reporting something missing from it
+pub fn end_of(s: &str) -> &str {
+    &s[s.len()..]
+}
+
+// Strips the expected prefix from rest, a slice of text
+pub fn literal<'a>(text: &str, rest: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    rest.strip_prefix(prefix).ok_or_else(|| {
+        let line = rest.lines().next().unwrap_or("");
+        let len = line.char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .take_while(|&end| end <= prefix.len())
+            .last()
+            .unwrap_or(0);
+        ParseError::at(text, &line[..len], format!("{:?}", prefix))
+    })
+}
+
+// Parses token, a slice of text, as a number
+pub fn number<T: FromStr>(text: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(text, token, "a number"))
+}
+
+// Splits rest, a slice of text, around the first occurrence of delimiter
+pub fn until<'a>(text: &str, rest: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    rest.split_once(delimiter).ok_or_else(|| {
+        let line = rest.lines().next().unwrap_or(rest);
+        ParseError::at(text, end_of(line), format!("{:?}", delimiter))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{literal, number, ParseError};
+
+    #[test]
+    fn locates_token() {
+        let input = "move 1 from 2 to 3\nmove x from 1 to 2\n";
+        let token = &input[24..25];
+        let error = number::<usize>(input, token).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.found, "\"x\"");
+        assert_eq!(
+            error.to_string(),
+            "expected a number, found \"x\"\n --> <input>:2:6\n  |\n2 | move x from 1 to 2\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn relocates_within_outer() {
+        let input = "abc\ndef ghi\n";
+        let line = &input[4..11];
+        let error = literal(line, &line[4..], "xyz").unwrap_err().within(input, line);
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.found, "\"ghi\"");
+    }
+
+    #[test]
+    fn reports_end_of_input() {
+        let input = "abc";
+        let error = ParseError::at(input, &input[3..], "\"d\"");
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.found, "end of input");
+    }
+}
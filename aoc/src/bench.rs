@@ -0,0 +1,87 @@
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+use std::time::Duration;
+use crate::solver::Part;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{}", part.number()),
+        }
+    }
+}
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Stats { min: sorted[0], median, max: sorted[sorted.len() - 1] }
+    }
+}
+
+// One row of benchmark results: the timings of a single phase of one implementation of a day
+pub struct BenchResult {
+    pub day: u8,
+    pub variant: &'static str,
+    pub phase: Phase,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+pub fn write_csv(out: &mut impl Write, results: &[BenchResult]) -> io::Result<()> {
+    writeln!(out, "day,variant,phase,iterations,min_ns,median_ns,max_ns")?;
+    for r in results {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            r.day, r.variant, r.phase, r.iterations,
+            r.stats.min.as_nanos(), r.stats.median.as_nanos(), r.stats.max.as_nanos(),
+        )?;
+    }
+    Ok(())
+}
+
+pub fn print_table(results: &[BenchResult]) {
+    println!("{:>3}  {:<10} {:<6} {:>12} {:>12} {:>12}", "day", "variant", "phase", "min", "median", "max");
+    for r in results {
+        println!(
+            "{:>3}  {:<10} {:<6} {:>12} {:>12} {:>12}",
+            r.day, r.variant, r.phase.to_string(),
+            format!("{:.3?}", r.stats.min), format!("{:.3?}", r.stats.median), format!("{:.3?}", r.stats.max),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::Stats;
+
+    #[test]
+    fn median_of_odd_and_even_samples() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(8)));
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day01::{top_elves, top_total, ElfStats, Error};
+use crate::solver::Solver;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input<'a> = Vec<ElfStats>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        top_elves(input.as_bytes(), 3).map_err(|e| match e {
+            Error::Parse(e) => e,
+            Error::Io(e) => unreachable!("reading from a string failed: {}", e),
+        })
+    }
+
+    fn part1(&self, top: &Self::Input<'_>) -> impl Display {
+        top_total(top, 1)
+    }
+
+    fn part2(&self, top: &Self::Input<'_>) -> impl Display {
+        top_total(top, 3)
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day02::{parse_guide, total_score_as_moves, total_score_as_results, RPS};
+use crate::solver::Solver;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input<'a> = Vec<(RPS, &'a str)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_guide(input)
+    }
+
+    fn part1(&self, guide: &Self::Input<'_>) -> impl Display {
+        total_score_as_moves(guide)
+    }
+
+    fn part2(&self, guide: &Self::Input<'_>) -> impl Display {
+        total_score_as_results(guide)
+    }
+}
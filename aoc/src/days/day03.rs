@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day03::{parse_rucksacks, total_badge_priority, total_misplaced_priority, Rucksack, ELF_GROUP_SIZE};
+use crate::solver::Solver;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input<'a> = Vec<Rucksack>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_rucksacks(input, ELF_GROUP_SIZE)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input<'_>) -> impl Display {
+        total_misplaced_priority(rucksacks)
+    }
+
+    fn part2(&self, rucksacks: &Self::Input<'_>) -> impl Display {
+        total_badge_priority(rucksacks, ELF_GROUP_SIZE)
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day04::{count_fully_contained, count_overlapping, parse_range_pairs, RangePair};
+use crate::solver::Solver;
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input<'a> = Vec<RangePair>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_range_pairs(input)
+    }
+
+    fn part1(&self, range_pairs: &Self::Input<'_>) -> impl Display {
+        count_fully_contained(range_pairs)
+    }
+
+    fn part2(&self, range_pairs: &Self::Input<'_>) -> impl Display {
+        count_overlapping(range_pairs)
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day05::crane::{Crane, CrateMover9000, CrateMover9001};
+use day05::{parse_input, tops_of_stacks, ProcStep, Stacks};
+use crate::solver::Solver;
+
+pub struct Day05;
+
+// The tops of the stacks once the crane's finished, or why it couldn't
+fn tops_after(crane: &impl Crane, (initial_stacks, proc_steps): &(Stacks, Vec<ProcStep>)) -> String {
+    let mut stacks = initial_stacks.clone();
+    match crane.apply(&mut stacks, proc_steps) {
+        Ok(()) => tops_of_stacks(&stacks),
+        Err(e) => e.to_string(),
+    }
+}
+
+impl Solver for Day05 {
+    type Input<'a> = (Stacks, Vec<ProcStep>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> impl Display {
+        tops_after(&CrateMover9000, input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> impl Display {
+        tops_after(&CrateMover9001, input)
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day06::{find_position_of_marker_by_windows, Datastream, MESSAGE_MARKER_LEN, PACKET_MARKER_LEN};
+use crate::solver::Solver;
+
+// The end of the first marker, if there is one
+fn describe(position: Option<usize>) -> String {
+    position.map_or("no marker".to_string(), |p| p.to_string())
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input<'a> = Datastream<'a>;
+
+    const VARIANT: &'static str = "last-seen";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Datastream::parse(input)
+    }
+
+    fn part1(&self, datastream: &Self::Input<'_>) -> impl Display {
+        describe(datastream.packet_markers().next())
+    }
+
+    fn part2(&self, datastream: &Self::Input<'_>) -> impl Display {
+        describe(datastream.message_markers().next())
+    }
+}
+
+// A second implementation, just for fun, which checks every window of the same datastream
+pub struct Day06Windows;
+
+impl Solver for Day06Windows {
+    type Input<'a> = Datastream<'a>;
+
+    const VARIANT: &'static str = "windows";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Datastream::parse(input)
+    }
+
+    fn part1(&self, datastream: &Self::Input<'_>) -> impl Display {
+        describe(find_position_of_marker_by_windows(datastream.as_str(), PACKET_MARKER_LEN))
+    }
+
+    fn part2(&self, datastream: &Self::Input<'_>) -> impl Display {
+        describe(find_position_of_marker_by_windows(datastream.as_str(), MESSAGE_MARKER_LEN))
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day07::{parse_transcript, Error, FsTree};
+use crate::solver::Solver;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input<'a> = FsTree;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_transcript(input).map_err(|e| match e {
+            Error::Parse(e) => e,
+            Error::Transcript { line, problem } => {
+                let text = input.lines().nth(line - 1).unwrap_or_default();
+                ParseError::at(input, text, format!("a line that fits the transcript so far ({})", problem))
+            },
+        })
+    }
+
+    fn part1(&self, tree: &Self::Input<'_>) -> impl Display {
+        tree.sum_of_dirs_not_more_than(100000)
+    }
+
+    fn part2(&self, tree: &Self::Input<'_>) -> impl Display {
+        tree.smallest_directory_freeing_up_to(30000000)
+            .map_or("no directory is big enough".to_string(), |size| size.to_string())
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt::Display;
+use aoc_common::{Grid, ParseError};
+use day08::{max_scenic_score, num_visible, parse_grid, scan_grid_all_directions, GridCell};
+use crate::solver::Solver;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input<'a> = Grid<GridCell>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let mut grid = parse_grid(input)?;
+        scan_grid_all_directions(&mut grid);
+        Ok(grid)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> impl Display {
+        num_visible(grid)
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> impl Display {
+        max_scenic_score(grid)
+    }
+}
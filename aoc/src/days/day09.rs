@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::{Direction, ParseError};
+use day09::{count_tail_positions, parse_moves};
+use crate::solver::Solver;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input<'a> = Vec<(Direction, u32)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part1(&self, moves: &Self::Input<'_>) -> impl Display {
+        count_tail_positions(moves, 2)
+    }
+
+    fn part2(&self, moves: &Self::Input<'_>) -> impl Display {
+        count_tail_positions(moves, 10)
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day10::{parse_program, run_program, Instruction};
+use crate::solver::Solver;
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_program(input)
+    }
+
+    fn part1(&self, program: &Self::Input<'_>) -> impl Display {
+        run_program(program).signal_strength
+    }
+
+    fn part2(&self, program: &Self::Input<'_>) -> impl Display {
+        run_program(program).render_screen()
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day11::{calc_monkey_business, parse_monkeys, test_product, Monkey};
+use crate::solver::Solver;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input<'_>) -> impl Display {
+        calc_monkey_business(&mut monkeys.clone(), 20, |w| w / 3)
+    }
+
+    fn part2(&self, monkeys: &Self::Input<'_>) -> impl Display {
+        let test_product = test_product(monkeys);
+        calc_monkey_business(&mut monkeys.clone(), 10_000, |w| w % test_product)
+    }
+}
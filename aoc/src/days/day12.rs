@@ -0,0 +1,26 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day12::Heightmap;
+use crate::solver::Solver;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input<'a> = Heightmap;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Heightmap::parse(input)
+    }
+
+    fn part1(&self, heightmap: &Self::Input<'_>) -> impl Display {
+        let mut heightmap = heightmap.clone();
+        heightmap.calculate_shortest_paths();
+        heightmap.shortest_path_from_start()
+    }
+
+    fn part2(&self, heightmap: &Self::Input<'_>) -> impl Display {
+        let mut heightmap = heightmap.clone();
+        heightmap.calculate_shortest_paths();
+        heightmap.shortest_path_from_any_lowest()
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day13::{decoder_key, parse_packets, sum_of_ordered_pair_indices, PacketNode};
+use crate::solver::Solver;
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input<'a> = Vec<PacketNode>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_packets(input)
+    }
+
+    fn part1(&self, packets: &Self::Input<'_>) -> impl Display {
+        sum_of_ordered_pair_indices(packets)
+    }
+
+    fn part2(&self, packets: &Self::Input<'_>) -> impl Display {
+        decoder_key(packets)
+    }
+}
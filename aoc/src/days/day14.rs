@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day14::Cave;
+use crate::solver::Solver;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input<'a> = Cave;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Cave::parse(input)
+    }
+
+    fn part1(&self, cave: &Self::Input<'_>) -> impl Display {
+        cave.clone().pour_sand(true)
+    }
+
+    fn part2(&self, cave: &Self::Input<'_>) -> impl Display {
+        cave.clone().pour_sand(false)
+    }
+}
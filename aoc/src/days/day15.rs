@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day15::{count_positions_without_beacon, find_distress_beacon, parse_sensors, tuning_frequency, Sensor};
+use crate::solver::{Params, Solver};
+
+pub struct Day15;
+
+// The sensors, and which part of the area the puzzle asks about. Nothing in the input says, so the
+// example (row 10 and a search area up to 20) and other small inputs set them with params.
+pub struct Survey {
+    sensors: Vec<Sensor>,
+    target_row: isize,
+    search_max: isize,
+}
+
+impl Solver for Day15 {
+    type Input<'a> = Survey;
+
+    const PARAMS: &'static [&'static str] = &["row", "search-max"];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        self.parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(&self, input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Survey {
+            sensors: parse_sensors(input)?,
+            target_row: params.get("row").unwrap_or(2_000_000) as isize,
+            search_max: params.get("search-max").unwrap_or(4_000_000) as isize,
+        })
+    }
+
+    fn part1(&self, survey: &Self::Input<'_>) -> impl Display {
+        count_positions_without_beacon(&survey.sensors, survey.target_row)
+    }
+
+    fn part2(&self, survey: &Self::Input<'_>) -> impl Display {
+        find_distress_beacon(&survey.sensors, survey.search_max)
+            .map_or("no uncovered position".to_string(), |beacon| tuning_frequency(&beacon).to_string())
+    }
+}
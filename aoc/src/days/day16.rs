@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day16::precalc::{calculate_all_shortest_distances, max_pressure_release, max_pressure_release_by_two_agents, parse_valves, usable_valve_indexes, Valve};
+use day16::recursive;
+use crate::solver::Solver;
+
+pub struct Day16;
+
+pub struct Tunnels {
+    valves: Vec<Valve>,
+    start_valve_index: usize,
+    min_dists: Vec<Vec<usize>>,
+    usable_valve_indexes: Vec<usize>,
+}
+
+impl Solver for Day16 {
+    type Input<'a> = Tunnels;
+
+    const VARIANT: &'static str = "precalc";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let (valves, valve_index_by_name) = parse_valves(input)?;
+        let min_dists = calculate_all_shortest_distances(&valves, &valve_index_by_name);
+        let usable_valve_indexes = usable_valve_indexes(&valves);
+        Ok(Tunnels { start_valve_index: valve_index_by_name["AA"], valves, min_dists, usable_valve_indexes })
+    }
+
+    fn part1(&self, tunnels: &Self::Input<'_>) -> impl Display {
+        max_pressure_release(&tunnels.valves, &tunnels.usable_valve_indexes, tunnels.start_valve_index, &tunnels.min_dists, 30)
+    }
+
+    fn part2(&self, tunnels: &Self::Input<'_>) -> impl Display {
+        max_pressure_release_by_two_agents(&tunnels.valves, &tunnels.usable_valve_indexes, tunnels.start_valve_index, &tunnels.min_dists, 26)
+    }
+}
+
+// The original, much slower, implementation, which searches recursively over every order of valves
+pub struct Day16Recursive;
+
+pub struct RecursiveTunnels {
+    valves: Vec<recursive::Valve>,
+    start_valve_index: usize,
+    dists: Vec<Vec<usize>>,
+    usable_valve_indexes: Vec<usize>,
+    all_open: u64,
+}
+
+impl Solver for Day16Recursive {
+    type Input<'a> = RecursiveTunnels;
+
+    const VARIANT: &'static str = "recursive";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let (valves, valve_index_by_name) = recursive::parse_valves(input)?;
+        let dists = recursive::calculate_all_shortest_distances(&valves, &valve_index_by_name);
+        let usable_valve_indexes = recursive::usable_valve_indexes(&valves);
+        let all_open = recursive::all_open(&usable_valve_indexes);
+        Ok(RecursiveTunnels { start_valve_index: valve_index_by_name["AA"], valves, dists, usable_valve_indexes, all_open })
+    }
+
+    fn part1(&self, tunnels: &Self::Input<'_>) -> impl Display {
+        recursive::max_released_pressure_solo(
+            tunnels.start_valve_index,
+            30,
+            0,
+            0,
+            tunnels.all_open,
+            &tunnels.valves,
+            &tunnels.usable_valve_indexes,
+            &tunnels.dists,
+        )
+    }
+
+    fn part2(&self, tunnels: &Self::Input<'_>) -> impl Display {
+        recursive::max_released_pressure_duo(
+            tunnels.start_valve_index,
+            tunnels.start_valve_index,
+            26,
+            26,
+            0,
+            0,
+            tunnels.all_open,
+            &tunnels.valves,
+            &tunnels.usable_valve_indexes,
+            &tunnels.dists,
+            &mut HashMap::new(),
+        )
+    }
+}
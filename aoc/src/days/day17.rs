@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day17::{parse_jet_dirs, Chamber};
+use crate::solver::Solver;
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input<'a> = &'a [u8];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_jet_dirs(input)
+    }
+
+    fn part1(&self, jet_dirs: &Self::Input<'_>) -> impl Display {
+        Chamber::new().calc_height_after_rounds(jet_dirs, 2022)
+    }
+
+    fn part2(&self, jet_dirs: &Self::Input<'_>) -> impl Display {
+        Chamber::new().calc_height_after_rounds(jet_dirs, 1000000000000)
+    }
+}
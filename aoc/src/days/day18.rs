@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use aoc_common::{Coord3, ParseError};
+use day18::{exterior_surface_area, parse_cubes, surface_area};
+use crate::solver::Solver;
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input<'a> = HashSet<Coord3>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_cubes(input)
+    }
+
+    fn part1(&self, cubes: &Self::Input<'_>) -> impl Display {
+        surface_area(cubes)
+    }
+
+    fn part2(&self, cubes: &Self::Input<'_>) -> impl Display {
+        exterior_surface_area(cubes)
+    }
+}
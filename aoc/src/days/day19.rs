@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day19::{parse_blueprints, product_of_first_three_maximal_geodes, sum_quality_levels, Blueprint};
+use crate::solver::Solver;
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_blueprints(input)
+    }
+
+    fn part1(&self, blueprints: &Self::Input<'_>) -> impl Display {
+        sum_quality_levels(blueprints, 24)
+    }
+
+    fn part2(&self, blueprints: &Self::Input<'_>) -> impl Display {
+        product_of_first_three_maximal_geodes(blueprints, 32)
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day20::{decrypt, parse_tracked_numbers};
+use crate::solver::Solver;
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input<'a> = Vec<(isize, usize)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_tracked_numbers(input)
+    }
+
+    fn part1(&self, tracked_numbers: &Self::Input<'_>) -> impl Display {
+        decrypt(tracked_numbers, 1, 1)
+    }
+
+    fn part2(&self, tracked_numbers: &Self::Input<'_>) -> impl Display {
+        decrypt(tracked_numbers, 811589153, 10)
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day21::{calculate, find_humn_dependents, invert_root, parse, Node};
+use crate::solver::Solver;
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input<'a> = HashMap<String, Node>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, lookup: &Self::Input<'_>) -> impl Display {
+        calculate(&"root".to_string(), lookup)
+    }
+
+    fn part2(&self, lookup: &Self::Input<'_>) -> impl Display {
+        let dependents = find_humn_dependents(lookup);
+        invert_root(lookup, &dependents)
+    }
+}
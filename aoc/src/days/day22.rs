@@ -0,0 +1,26 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day22::{convert_to_cube, follow_instructions, parse_input, Agent, Instruction, WrapMap};
+use crate::solver::Solver;
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input<'a> = (Agent, WrapMap, Vec<Instruction>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, notes: &Self::Input<'_>) -> impl Display {
+        let (agent, map, instructions) = notes;
+        follow_instructions(agent, map, instructions)
+    }
+
+    fn part2(&self, notes: &Self::Input<'_>) -> impl Display {
+        let (agent, map, instructions) = notes;
+        let mut cube_map = map.clone();
+        convert_to_cube(&mut cube_map);
+        follow_instructions(agent, &cube_map, instructions)
+    }
+}
@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use aoc_common::{Coord, ParseError};
+use day23::{empty_spaces_after_rounds, first_round_without_moves, parse_elves};
+use crate::solver::Solver;
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input<'a> = HashSet<Coord>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_elves(input)
+    }
+
+    fn part1(&self, elves: &Self::Input<'_>) -> impl Display {
+        empty_spaces_after_rounds(elves, 10)
+    }
+
+    fn part2(&self, elves: &Self::Input<'_>) -> impl Display {
+        first_round_without_moves(elves)
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day24::rewind::{time_to_exit, time_to_exit_return_and_exit_again, Map};
+use day24::snapshots::{Blizzards, Valley};
+use crate::solver::Solver;
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input<'a> = Map;
+
+    const VARIANT: &'static str = "rewind";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(&self, map: &Self::Input<'_>) -> impl Display {
+        time_to_exit(map)
+    }
+
+    fn part2(&self, map: &Self::Input<'_>) -> impl Display {
+        time_to_exit_return_and_exit_again(map)
+    }
+}
+
+// The original implementation, which precalculates every blizzard state
+pub struct Day24Snapshots;
+
+impl Solver for Day24Snapshots {
+    type Input<'a> = (Valley, Vec<Blizzards>);
+
+    const VARIANT: &'static str = "snapshots";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let valley = Valley::parse(input)?;
+        let all_blizzards = valley.all_blizzards();
+        Ok((valley, all_blizzards))
+    }
+
+    fn part1(&self, (valley, all_blizzards): &Self::Input<'_>) -> impl Display {
+        valley.time_to_exit(all_blizzards)
+    }
+
+    fn part2(&self, (valley, all_blizzards): &Self::Input<'_>) -> impl Display {
+        valley.time_to_exit_return_and_exit_again(all_blizzards)
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+use aoc_common::ParseError;
+use day25::{decimal_to_snafu, parse_snafu_numbers, sum_of_snafu_numbers};
+use crate::solver::{Part, Solver};
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input<'a> = Vec<&'a str>;
+
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_snafu_numbers(input)
+    }
+
+    fn part1(&self, snafu_numbers: &Self::Input<'_>) -> impl Display {
+        decimal_to_snafu(sum_of_snafu_numbers(snafu_numbers))
+    }
+
+    // Never called, as PARTS only lists part 1
+    fn part2(&self, _input: &Self::Input<'_>) -> impl Display {
+        ""
+    }
+}
@@ -0,0 +1,82 @@
+use std::io::{self, Write};
+use serde::Serialize;
+use day01::ElfStats;
+
+// One row of the day 1 ranking: an elf, and where its items are in the input
+#[derive(Serialize)]
+pub struct RankedElf {
+    pub rank: usize,
+    pub index: usize,
+    pub first_line: usize,
+    pub last_line: usize,
+    pub items: usize,
+    pub total: u64,
+}
+
+// Ranks elves already sorted by the calories they carry, from 1 for the most
+pub fn rank(elves: &[ElfStats]) -> Vec<RankedElf> {
+    elves.iter()
+        .enumerate()
+        .map(|(i, elf)| RankedElf {
+            rank: i + 1,
+            index: elf.index,
+            first_line: elf.first_line,
+            last_line: elf.last_line,
+            items: elf.count,
+            total: elf.total,
+        })
+        .collect()
+}
+
+pub fn write_csv(out: &mut impl Write, elves: &[RankedElf]) -> io::Result<()> {
+    writeln!(out, "rank,index,first_line,last_line,items,total")?;
+    for e in elves {
+        writeln!(out, "{},{},{},{},{},{}", e.rank, e.index, e.first_line, e.last_line, e.items, e.total)?;
+    }
+    Ok(())
+}
+
+// One JSON object per line for each elf
+pub fn write_json(out: &mut impl Write, elves: &[RankedElf]) -> io::Result<()> {
+    for e in elves {
+        writeln!(out, "{}", serde_json::to_string(e).expect("A ranked elf can always be serialised"))?;
+    }
+    Ok(())
+}
+
+pub fn print_table(elves: &[RankedElf]) {
+    println!("{:>6} {:>6} {:>15} {:>6} {:>10}", "rank", "index", "lines", "items", "total");
+    for e in elves {
+        println!(
+            "{:>6} {:>6} {:>15} {:>6} {:>10}",
+            e.rank, e.index, format!("{}-{}", e.first_line, e.last_line), e.items, e.total,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use day01::top_elves;
+    use super::{rank, write_csv, write_json};
+
+    const EXAMPLE: &str = include_str!("../../day01/example.txt");
+
+    #[test]
+    fn exports_top_elves() {
+        let elves = rank(&top_elves(EXAMPLE.as_bytes(), 2).unwrap());
+
+        let mut csv = vec![];
+        write_csv(&mut csv, &elves).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "rank,index,first_line,last_line,items,total\n1,3,10,12,3,24000\n2,2,7,8,2,11000\n"
+        );
+
+        let mut json = vec![];
+        write_json(&mut json, &elves[..1]).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "{\"rank\":1,\"index\":3,\"first_line\":10,\"last_line\":12,\"items\":3,\"total\":24000}\n"
+        );
+    }
+}
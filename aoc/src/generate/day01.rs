@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of elves
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let items = rng.gen_range(1..=15);
+            (0..items).map(|_| format!("{}\n", rng.gen_range(1000..=60000))).collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
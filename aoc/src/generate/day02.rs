@@ -0,0 +1,9 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of rounds
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}\n", rng.gen_range('A'..='C'), rng.gen_range('X'..='Z')))
+        .collect()
+}
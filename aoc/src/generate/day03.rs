@@ -0,0 +1,47 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// size: the number of groups of three elves
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    let mut out = String::new();
+    for _ in 0..size {
+        // Each elf in the group draws from its own pool of items, so the badge is the only item
+        // all three share
+        items.shuffle(rng);
+        let (badge, pools) = (items[0], &items[1..]);
+        for pool in pools.chunks(pools.len() / 3) {
+            out.push_str(&rucksack(rng, badge, pool));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+// A rucksack containing the badge, in which exactly one item appears in both compartments
+fn rucksack(rng: &mut StdRng, badge: char, pool: &[char]) -> String {
+    let mut pool = pool.to_vec();
+    pool.push(badge);
+    pool.shuffle(rng);
+    let shared = pool[0];
+    let (left_only, right_only) = pool[1..].split_at(pool.len() / 2);
+    // Make sure the badge is in one of the compartments, whichever it's been given to
+    let required_left = if left_only.contains(&badge) { vec![shared, badge] } else { vec![shared] };
+    let required_right = if right_only.contains(&badge) { vec![shared, badge] } else { vec![shared] };
+
+    let len = rng.gen_range(2..=16);
+    let mut left = compartment(rng, len, &required_left, left_only);
+    let right = compartment(rng, len, &required_right, right_only);
+    left.push_str(&right);
+    left
+}
+
+fn compartment(rng: &mut StdRng, len: usize, required: &[char], others: &[char]) -> String {
+    let mut items = required.to_vec();
+    while items.len() < len {
+        items.push(*others.choose(rng).unwrap_or(&required[0]));
+    }
+    items.shuffle(rng);
+    items.into_iter().collect()
+}
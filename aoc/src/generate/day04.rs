@@ -0,0 +1,14 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of pairs of elves
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{},{}\n", assignment(rng), assignment(rng)))
+        .collect()
+}
+
+fn assignment(rng: &mut StdRng) -> String {
+    let start = rng.gen_range(1..=99);
+    format!("{}-{}", start, rng.gen_range(start..=99))
+}
@@ -0,0 +1,36 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use day05::{draw_stacks, Stacks};
+
+// size: the number of moves. The moves never empty a stack, so that there's always a crate on top of
+// each at the end.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let num_stacks = rng.gen_range(2..=9);
+    // The first stack has at least two crates, so there's always one that can be moved
+    let stacks: Stacks = (0..num_stacks)
+        .map(|i| (0..rng.gen_range(if i == 0 { 2 } else { 1 }..=8)).map(|_| rng.gen_range('A'..='Z')).collect())
+        .collect();
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<_>>();
+
+    let mut out = draw_stacks(&stacks);
+    out.push('\n');
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.gen_range(0..heights.len());
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = loop {
+            let to = rng.gen_range(0..heights.len());
+            if to != from {
+                break to;
+            }
+        };
+        let num = rng.gen_range(1..heights[from]);
+        heights[from] -= num;
+        heights[to] += num;
+        out.push_str(&format!("move {} from {} to {}\n", num, from + 1, to + 1));
+    }
+    out
+}
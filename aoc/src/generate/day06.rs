@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// size: the length of the datastream (at least 14)
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(14);
+    let mut letters = ('a'..='z').collect::<Vec<_>>();
+    letters.shuffle(rng);
+
+    // Most of the stream only uses a few letters, so that it can't contain a start-of-message
+    // marker, which is then placed somewhere in the latter half
+    let common = &letters[..rng.gen_range(5..=10)];
+    let mut stream = (0..size).map(|_| *common.choose(rng).unwrap()).collect::<Vec<_>>();
+    let marker_start = rng.gen_range((size - 14) / 2..=size - 14);
+    letters.shuffle(rng);
+    stream[marker_start..marker_start + 14].copy_from_slice(&letters[..14]);
+
+    let mut out = stream.into_iter().collect::<String>();
+    out.push('\n');
+    out
+}
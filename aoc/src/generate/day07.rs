@@ -0,0 +1,71 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+struct Dir {
+    name: String,
+    children: Vec<usize>,
+    files: Vec<(String, u64)>,
+}
+
+// size: the number of files. The total size is kept between 40000000 and 70000000, so that the disk
+// isn't over-full but does still need space freeing for the update.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut dirs = vec![Dir { name: "/".to_string(), children: vec![], files: vec![] }];
+    for _ in 0..size / 3 {
+        let parent = rng.gen_range(0..dirs.len());
+        let name = unique_name(rng, &dirs, parent, false);
+        let index = dirs.len();
+        dirs[parent].children.push(index);
+        dirs.push(Dir { name, children: vec![], files: vec![] });
+    }
+
+    let weights = (0..size).map(|_| rng.gen_range(1..=1000u64)).collect::<Vec<_>>();
+    let total_weight = weights.iter().sum::<u64>().max(1);
+    let total_size = rng.gen_range(41_000_000..=69_000_000);
+    for weight in weights {
+        let dir = rng.gen_range(0..dirs.len());
+        let name = unique_name(rng, &dirs, dir, true);
+        dirs[dir].files.push((name, (weight * total_size / total_weight).max(1)));
+    }
+
+    let mut out = String::new();
+    out.push_str("$ cd /\n");
+    list(rng, &dirs, 0, &mut out);
+    out
+}
+
+fn unique_name(rng: &mut StdRng, dirs: &[Dir], parent: usize, is_file: bool) -> String {
+    loop {
+        let len = rng.gen_range(1..=8);
+        let mut name = (0..len).map(|_| rng.gen_range('a'..='z')).collect::<String>();
+        if is_file && rng.gen_bool(0.5) {
+            name.push('.');
+            name.push_str(["txt", "dat", "log", "lst", "ext"].choose(rng).unwrap());
+        }
+        let dir = &dirs[parent];
+        let taken = dir.files.iter().any(|(n, _)| n == &name) ||
+            dir.children.iter().any(|&c| dirs[c].name == name);
+        if !taken {
+            return name;
+        }
+    }
+}
+
+fn list(rng: &mut StdRng, dirs: &[Dir], index: usize, out: &mut String) {
+    let dir = &dirs[index];
+    let mut entries = dir.children.iter().map(|&c| format!("dir {}", dirs[c].name))
+        .chain(dir.files.iter().map(|(name, size)| format!("{} {}", size, name)))
+        .collect::<Vec<_>>();
+    entries.shuffle(rng);
+    out.push_str("$ ls\n");
+    for entry in entries {
+        out.push_str(&entry);
+        out.push('\n');
+    }
+    for &child in &dir.children {
+        out.push_str(&format!("$ cd {}\n", dirs[child].name));
+        list(rng, dirs, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
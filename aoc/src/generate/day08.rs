@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the side of the (square) grid of trees
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row = (0..size).map(|_| rng.gen_range('0'..='9')).collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,10 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// size: the number of moves of the head
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}\n", ["L", "R", "U", "D"].choose(rng).unwrap(), rng.gen_range(1..=20)))
+        .collect()
+}
@@ -0,0 +1,23 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: unused, as the program always runs for exactly the 240 cycles it takes to draw the screen
+pub fn generate(rng: &mut StdRng, _size: usize) -> String {
+    let mut out = String::new();
+    let mut cycles = 0;
+    let mut x = 1;
+    while cycles < 240 {
+        if cycles <= 238 && rng.gen_bool(0.6) {
+            // Keep the sprite on the screen, so that something gets drawn
+            let new_x = rng.gen_range((x - 15).max(0)..=(x + 15).min(39));
+            let v = new_x - x;
+            x = new_x;
+            out.push_str(&format!("addx {}\n", v));
+            cycles += 2;
+        } else {
+            out.push_str("noop\n");
+            cycles += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,92 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add(u64),
+    Times(u64),
+    Square,
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    op: Op,
+    test: (u64, usize, usize),
+}
+
+// size: the number of monkeys, from 4 up to 8. The divisors are distinct primes, which keeps their
+// product small enough for part two's worry levels to stay well within a u64.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.clamp(4, PRIMES.len());
+    loop {
+        let monkeys = random_monkeys(rng, size);
+        // Part one doesn't keep worry levels in check, so only use monkeys that don't overflow them
+        if !overflows_part1(&monkeys) {
+            return monkeys.iter().enumerate()
+                .map(|(i, m)| describe(i, m))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    }
+}
+
+fn random_monkeys(rng: &mut StdRng, size: usize) -> Vec<Monkey> {
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+    // As in the real puzzle, one monkey squares the worry level. Nothing throws to it, as any item
+    // squared more than a couple of times would overflow in part one.
+    let squarer = rng.gen_range(0..size);
+    (0..size)
+        .map(|i| {
+            let items = (0..rng.gen_range(1..=8)).map(|_| rng.gen_range(50..=99)).collect();
+            let op = if i == squarer {
+                Op::Square
+            } else if rng.gen_bool(0.3) {
+                Op::Times(rng.gen_range(2..=19))
+            } else {
+                Op::Add(rng.gen_range(1..=8))
+            };
+            let mut targets = (0..size).filter(|&t| t != i && t != squarer).collect::<Vec<_>>();
+            targets.shuffle(rng);
+            Monkey { items, op, test: (primes[i], targets[0], targets[1]) }
+        })
+        .collect()
+}
+
+fn overflows_part1(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys.iter().map(|m| m.items.clone()).collect::<Vec<_>>();
+    for _ in 0..20 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for worry in std::mem::take(&mut items[i]) {
+                let worry = match monkey.op {
+                    Op::Add(n) => worry.checked_add(n),
+                    Op::Times(n) => worry.checked_mul(n),
+                    Op::Square => worry.checked_mul(worry),
+                };
+                let Some(worry) = worry.map(|w| w / 3) else {
+                    return true;
+                };
+                let (div, if_true, if_false) = monkey.test;
+                items[if worry % div == 0 { if_true } else { if_false }].push(worry);
+            }
+        }
+    }
+    false
+}
+
+fn describe(index: usize, monkey: &Monkey) -> String {
+    let items = monkey.items.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(", ");
+    let op = match monkey.op {
+        Op::Add(n) => format!("+ {}", n),
+        Op::Times(n) => format!("* {}", n),
+        Op::Square => "* old".to_string(),
+    };
+    let (div, if_true, if_false) = monkey.test;
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        index, items, op, div, if_true, if_false,
+    )
+}
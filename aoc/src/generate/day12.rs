@@ -0,0 +1,34 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the width of the heightmap, which is a quarter as tall. A path from S to E that climbs a
+// step at a time is laid through otherwise random heights, so that E can always be reached.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(24);
+    let height = (width / 4).max(5);
+    let mut grid = (0..height)
+        .map(|_| (0..width).map(|_| rng.gen_range(b'a'..=b'z')).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // A staircase from the top left to the bottom right, long enough to climb from a to z after
+    // leaving S
+    let mut path = vec![(0, 0)];
+    let (mut x, mut y) = (0, 0);
+    while (x, y) != (width - 1, height - 1) {
+        if y == height - 1 || (x < width - 1 && rng.gen_bool(0.5)) {
+            x += 1;
+        } else {
+            y += 1;
+        }
+        path.push((x, y));
+    }
+    for (i, &(x, y)) in path.iter().enumerate().skip(1) {
+        grid[y][x] = b'a' + ((i - 1) * 25 / (path.len() - 2)) as u8;
+    }
+    grid[0][0] = b'S';
+    grid[height - 1][width - 1] = b'E';
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
@@ -0,0 +1,23 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of paths of rock
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (mut x, mut y) = (rng.gen_range(440..=560), rng.gen_range(13..=170));
+            let mut points = vec![format!("{},{}", x, y)];
+            let horizontal_first = rng.gen_bool(0.5);
+            for i in 0..rng.gen_range(1..=4) {
+                let delta = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                if (i % 2 == 0) == horizontal_first {
+                    x += delta;
+                } else {
+                    y = (y + delta).clamp(13, 170);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
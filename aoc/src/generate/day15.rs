@@ -0,0 +1,43 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// The example's search area, which keeps searching the sensors' borders quick. The inputs are solved
+// with the example's params to match.
+const SEARCH_MAX: isize = 20;
+
+// size: the number of sensors, besides the four that pin down the distress beacon
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let distress = (rng.gen_range(0..=SEARCH_MAX), rng.gen_range(0..=SEARCH_MAX));
+
+    // A sensor diagonally out from each corner of the distress beacon, just too short-sighted to
+    // see it, covers the rest of its quadrant of the search area
+    let mut sensors = vec![];
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let sensor = (distress.0 + dx * SEARCH_MAX, distress.1 + dy * SEARCH_MAX);
+        sensors.push((sensor, 2 * SEARCH_MAX - 1));
+    }
+    // Any others must not see it either
+    for _ in 0..size {
+        let sensor = (rng.gen_range(0..=SEARCH_MAX), rng.gen_range(0..=SEARCH_MAX));
+        let dist = manhattan_dist(sensor, distress);
+        if dist > 1 {
+            sensors.push((sensor, rng.gen_range(1..dist)));
+        }
+    }
+
+    sensors.into_iter()
+        .map(|(sensor, range)| {
+            let dx = rng.gen_range(-range..=range);
+            let dy = (range - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+            let beacon = (sensor.0 + dx, sensor.1 + dy);
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.0, sensor.1, beacon.0, beacon.1,
+            )
+        })
+        .collect()
+}
+
+fn manhattan_dist(a: (isize, isize), b: (isize, isize)) -> isize {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
@@ -0,0 +1,55 @@
+use std::collections::BTreeSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// size: the number of valves, from 2 up to 63 (as valves are tracked as bits of a u64). Up to 15
+// of them have working valves, as in the real puzzle, which keeps the search space manageable.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.clamp(2, 63);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < size {
+        let name = format!("{}{}", rng.gen_range('A'..='Z'), rng.gen_range('A'..='Z'));
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut rates = vec![0; size];
+    let working = ((size - 1) / 4).clamp(1, 15);
+    for i in rand::seq::index::sample(rng, size - 1, working).into_iter() {
+        rates[i + 1] = rng.gen_range(3..=25);
+    }
+
+    // A random spanning tree keeps every valve reachable, and a few more tunnels add some loops
+    let mut tunnels = vec![BTreeSet::new(); size];
+    let mut order = (0..size).collect::<Vec<_>>();
+    order.shuffle(rng);
+    for i in 1..size {
+        let (a, b) = (order[i], order[rng.gen_range(0..i)]);
+        tunnels[a].insert(b);
+        tunnels[b].insert(a);
+    }
+    for _ in 0..size / 3 {
+        let (a, b) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    }
+
+    let mut lines = (0..size)
+        .map(|i| {
+            let mut neighbours = tunnels[i].iter().map(|&n| names[n].as_str()).collect::<Vec<_>>();
+            neighbours.shuffle(rng);
+            let tunnels = if neighbours.len() == 1 {
+                format!("tunnel leads to valve {}", neighbours[0])
+            } else {
+                format!("tunnels lead to valves {}", neighbours.join(", "))
+            };
+            format!("Valve {} has flow rate={}; {}\n", names[i], rates[i], tunnels)
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+    lines.concat()
+}
@@ -0,0 +1,9 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of jets in the pattern
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut jets = (0..size.max(1)).map(|_| if rng.gen_bool(0.5) { '<' } else { '>' }).collect::<String>();
+    jets.push('\n');
+    jets
+}
@@ -0,0 +1,18 @@
+use std::collections::HashSet;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of cubes, which fill about a quarter of the space they're scattered through, so
+// that there are some air pockets trapped inside
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = ((size * 4) as f64).cbrt().ceil() as isize;
+    let mut cubes = HashSet::new();
+    let mut out = String::new();
+    while cubes.len() < size {
+        let cube = (rng.gen_range(0..side), rng.gen_range(0..side), rng.gen_range(0..side));
+        if cubes.insert(cube) {
+            out.push_str(&format!("{},{},{}\n", cube.0, cube.1, cube.2));
+        }
+    }
+    out
+}
@@ -0,0 +1,18 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of blueprints
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                id,
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4), rng.gen_range(5..=20),
+                rng.gen_range(2..=4), rng.gen_range(5..=20),
+            )
+        })
+        .collect()
+}
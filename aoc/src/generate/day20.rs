@@ -0,0 +1,18 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the number of numbers, exactly one of which is zero
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let zero = rng.gen_range(0..size);
+    (0..size)
+        .map(|i| {
+            let n = if i == zero {
+                0
+            } else {
+                rng.gen_range(1..=10000) * if rng.gen_bool(0.5) { 1 } else { -1 }
+            };
+            format!("{}\n", n)
+        })
+        .collect()
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// Keeps every value (and so every intermediate result) comfortably within an isize
+const MAX_VALUE: isize = 1_000_000_000_000;
+
+struct Monkeys {
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Monkeys {
+    fn name(&mut self, rng: &mut StdRng) -> String {
+        loop {
+            let name = (0..4).map(|_| rng.gen_range('a'..='z')).collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    // Adds a tree of about size monkeys not involving humn, returning its root and the number it yells
+    fn tree(&mut self, rng: &mut StdRng, size: usize) -> (String, isize) {
+        let name = self.name(rng);
+        if size <= 1 {
+            let n = rng.gen_range(1..=20);
+            self.jobs.push(format!("{}: {}", name, n));
+            return (name, n);
+        }
+
+        let left_size = rng.gen_range(1..size);
+        let (l, lv) = self.tree(rng, left_size);
+        let (r, rv) = self.tree(rng, size - left_size);
+        // Only divide exactly, as the real puzzle does, and don't let the numbers grow too large
+        let (op, value) = match rng.gen_range(0..4) {
+            0 if rv != 0 && lv % rv == 0 => ('/', lv / rv),
+            1 if lv.abs() <= MAX_VALUE / rv.abs().max(1) => ('*', lv * rv),
+            2 => ('-', lv - rv),
+            _ => ('+', lv + rv),
+        };
+        self.jobs.push(format!("{}: {} {} {}", name, l, op, r));
+        (name, value)
+    }
+
+    // Adds a tree of about size monkeys, one of which is humn, that yields target when humn yells the
+    // right number. humn is never a divisor, which would need the answer to divide exactly.
+    fn humn_tree(&mut self, rng: &mut StdRng, size: usize, target: isize) -> String {
+        if size <= 1 {
+            return "humn".to_string();
+        }
+
+        let name = self.name(rng);
+        let other_size = rng.gen_range(1..size);
+        let (other, v) = self.tree(rng, other_size);
+        let humn_left = rng.gen_bool(0.5);
+        let (op, humn_target) = match rng.gen_range(0..4) {
+            0 if v != 0 && target.abs() <= MAX_VALUE / v.abs() => ('/', target * v),
+            1 if v != 0 && target % v == 0 => ('*', target / v),
+            2 if humn_left => ('-', target + v),
+            2 => ('-', v - target),
+            _ => ('+', target - v),
+        };
+        let humn = self.humn_tree(rng, size - other_size, humn_target);
+        let (l, r) = if humn_left || op == '/' { (humn, other) } else { (other, humn) };
+        self.jobs.push(format!("{}: {} {} {}", name, l, op, r));
+        name
+    }
+}
+
+// size: roughly the number of monkeys (at least 5)
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    let mut monkeys = Monkeys { names: HashSet::new(), jobs: vec![] };
+
+    let other_size = rng.gen_range(1..size - 2);
+    let (other, target) = monkeys.tree(rng, other_size);
+    let humn = monkeys.humn_tree(rng, size - 1 - other_size, target);
+    let (l, r) = if rng.gen_bool(0.5) { (humn, other) } else { (other, humn) };
+    monkeys.jobs.push(format!("root: {} + {}", l, r));
+    monkeys.jobs.push(format!("humn: {}", rng.gen_range(1..=5000)));
+
+    monkeys.jobs.shuffle(rng);
+    monkeys.jobs.iter().map(|job| format!("{}\n", job)).collect()
+}
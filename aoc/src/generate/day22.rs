@@ -0,0 +1,32 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const FACE: usize = 50;
+
+// size: the number of moves in the path. The map is always folded the same way as the real puzzle's,
+// as that's the only cube net part two knows how to fold, but its walls are random.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // The columns of faces (as offset, width) in each band of rows of the net
+    let bands = [(1, 2), (1, 1), (0, 2), (0, 1)];
+    let mut out = String::new();
+    for (band, &(offset, faces)) in bands.iter().enumerate() {
+        for y in 0..FACE {
+            out.push_str(&" ".repeat(offset * FACE));
+            for x in 0..faces * FACE {
+                let is_start = band == 0 && y == 0 && x == 0;
+                out.push(if !is_start && rng.gen_bool(0.1) { '#' } else { '.' });
+            }
+            out.push('\n');
+        }
+    }
+
+    out.push('\n');
+    for i in 0..size.max(1) {
+        if i > 0 {
+            out.push(if rng.gen_bool(0.5) { 'L' } else { 'R' });
+        }
+        out.push_str(&rng.gen_range(1..=50).to_string());
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// size: the side of the (square) grove, about half of which is occupied by elves
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row = (0..size).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }).collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,73 @@
+use std::collections::{HashSet, VecDeque};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use day24::rewind::Map;
+
+// size: the width of the valley (at least 5), which is about a fifth as tall. As in the real puzzle,
+// no blizzards blow up or down the entrance and exit columns.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(5);
+    let height = (width / 5).max(5);
+    loop {
+        let valley = random_valley(rng, width, height);
+        // The solvers search until they find a way through, so only keep valleys that have one for
+        // all three trips
+        let map = Map::parse(&valley).expect("Generated valleys are valid");
+        let (entrance, exit) = ((1, 0), (width - 2, height - 1));
+        let there = earliest_arrival(&map, entrance, exit, 0);
+        let back = there.and_then(|t| earliest_arrival(&map, exit, entrance, t));
+        if back.and_then(|t| earliest_arrival(&map, entrance, exit, t)).is_some() {
+            return valley;
+        }
+    }
+}
+
+fn random_valley(rng: &mut StdRng, width: usize, height: usize) -> String {
+    let mut out = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let c = if (x, y) == (1, 0) || (x, y) == (width - 2, height - 1) {
+                '.'
+            } else if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+                '#'
+            } else if rng.gen_bool(0.3) {
+                let directions: &[char] = if x == 1 || x == width - 2 { &['<', '>'] } else { &['^', '>', 'v', '<'] };
+                *directions.choose(rng).unwrap()
+            } else {
+                '.'
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// The blizzards repeat, so there's no way through if every state within a cycle has been visited
+fn earliest_arrival(map: &Map, start: (usize, usize), end: (usize, usize), start_time: usize) -> Option<usize> {
+    let period = lcm(map.width - 2, map.height - 2);
+    let mut queue = VecDeque::from([(start, start_time)]);
+    let mut visited = HashSet::from([(start, start_time % period)]);
+    while let Some(((x, y), t)) = queue.pop_front() {
+        for next in map.open_neighbours(x, y, t + 1) {
+            if next == end {
+                return Some(t + 1);
+            }
+            if visited.insert((next, (t + 1) % period)) {
+                queue.push_back((next, t + 1));
+            }
+        }
+    }
+    None
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    a / gcd(a, b) * b
+}
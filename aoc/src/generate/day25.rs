@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use day25::decimal_to_snafu;
+
+// size: the number of fuel requirements
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let digits = rng.gen_range(1..=18);
+            format!("{}\n", decimal_to_snafu(rng.gen_range(1..5isize.pow(digits))))
+        })
+        .collect()
+}
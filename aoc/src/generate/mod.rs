@@ -0,0 +1,81 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use crate::solver::Params;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+// Makes random puzzle inputs in a day's format. What the size counts depends on the day (elves,
+// moves, the side of a grid, ...), and the inputs are always ones the puzzle could have given.
+pub struct Generator {
+    // Roughly the size of a real puzzle input
+    pub default_size: usize,
+    generate: fn(&mut StdRng, usize) -> String,
+    // The params to solve the inputs with, for days whose inputs aren't on the real puzzle's scale
+    params: &'static [(&'static str, i64)],
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+
+    pub fn params(&self) -> Params {
+        self.params.iter().copied().collect()
+    }
+}
+
+static GENERATORS: [Generator; 25] = [
+    Generator { default_size: 250, generate: day01::generate, params: &[] },
+    Generator { default_size: 2500, generate: day02::generate, params: &[] },
+    Generator { default_size: 100, generate: day03::generate, params: &[] },
+    Generator { default_size: 1000, generate: day04::generate, params: &[] },
+    Generator { default_size: 500, generate: day05::generate, params: &[] },
+    Generator { default_size: 4096, generate: day06::generate, params: &[] },
+    Generator { default_size: 300, generate: day07::generate, params: &[] },
+    Generator { default_size: 99, generate: day08::generate, params: &[] },
+    Generator { default_size: 2000, generate: day09::generate, params: &[] },
+    Generator { default_size: 0, generate: day10::generate, params: &[] },
+    Generator { default_size: 8, generate: day11::generate, params: &[] },
+    Generator { default_size: 160, generate: day12::generate, params: &[] },
+    Generator { default_size: 150, generate: day13::generate, params: &[] },
+    Generator { default_size: 150, generate: day14::generate, params: &[] },
+    Generator { default_size: 30, generate: day15::generate, params: &[("row", 10), ("search-max", 20)] },
+    Generator { default_size: 58, generate: day16::generate, params: &[] },
+    Generator { default_size: 10091, generate: day17::generate, params: &[] },
+    Generator { default_size: 2800, generate: day18::generate, params: &[] },
+    Generator { default_size: 30, generate: day19::generate, params: &[] },
+    Generator { default_size: 5000, generate: day20::generate, params: &[] },
+    Generator { default_size: 2000, generate: day21::generate, params: &[] },
+    Generator { default_size: 4000, generate: day22::generate, params: &[] },
+    Generator { default_size: 70, generate: day23::generate, params: &[] },
+    Generator { default_size: 122, generate: day24::generate, params: &[] },
+    Generator { default_size: 120, generate: day25::generate, params: &[] },
+];
+
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    (day as usize).checked_sub(1).and_then(|i| GENERATORS.get(i))
+}
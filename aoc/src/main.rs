@@ -0,0 +1,650 @@
+use std::fs::File;
+use std::io::{stdout, BufWriter, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use aoc_common::input::{open_input_file, read_input_file};
+use aoc_common::ParseError;
+use crate::bench::{print_table, write_csv, BenchResult, Stats};
+use crate::report::DayReport;
+use crate::solver::{Params, Part, Puzzle};
+use crate::verify::{diff, Answers};
+
+mod bench;
+mod days;
+mod elves;
+mod generate;
+mod report;
+mod solver;
+mod stress;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle for a single day, or for every day
+    Run(RunArgs),
+    /// Time the parsing and each part of a single day, or of every day
+    Bench(BenchArgs),
+    /// Check a single day, or every day, still gives the answers recorded in its answers.toml
+    Verify(VerifyArgs),
+    /// Print a random puzzle input for a day
+    Generate(GenerateArgs),
+    /// Solve random inputs with every implementation of a day, checking that they all agree
+    Stress(StressArgs),
+    /// Rank day 1's elves by the calories they carry
+    Elves(ElvesArgs),
+    /// Find the highest scoring moves against the opponent in day 2's strategy guide
+    Strategy(StrategyArgs),
+    /// List every shared item and badge candidate in day 3's rucksacks, flagging malformed lines
+    Rucksacks(RucksacksArgs),
+    /// Analyse every elf's assignment in day 4 together: the busiest sections, which elves overlap,
+    /// and which sections have one elf or none
+    Assignments(AssignmentsArgs),
+    /// Replay day 5's procedure, drawing the stacks after every step, or after a single one
+    Crates(CratesArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    days: DaySelection,
+
+    /// Only solve the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of the day's input.txt
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Set one of the day's params, such as the row day 15 asks about, instead of using the real
+    /// puzzle's
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, conflicts_with = "all")]
+    params: Vec<(String, i64)>,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// How many worker threads to solve the days on, defaulting to one per CPU
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    threads: Option<u32>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Readable text
+    Text,
+    /// One JSON object per line for each day, with its answers, the variant that found them and
+    /// the time taken in nanoseconds
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    days: DaySelection,
+
+    /// How many times to run each phase
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Also time the alternative implementations of days that have them
+    #[arg(long)]
+    variants: bool,
+
+    /// Write the results as CSV to this file, or to stdout if "-", instead of printing a table
+    #[arg(long, value_name = "PATH")]
+    csv: Option<PathBuf>,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of the day's input.txt
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Set one of the day's params, such as the row day 15 asks about, instead of using the real
+    /// puzzle's
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, conflicts_with = "all")]
+    params: Vec<(String, i64)>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    days: DaySelection,
+
+    /// Also check the alternative implementations of days that have them
+    #[arg(long)]
+    variants: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day to generate an input for
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[command(flatten)]
+    generation: GenerationArgs,
+}
+
+#[derive(Args)]
+struct StressArgs {
+    #[command(flatten)]
+    days: DaySelection,
+
+    #[command(flatten)]
+    generation: GenerationArgs,
+
+    /// How many inputs to try for each day, with consecutive seeds
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    cases: u64,
+}
+
+#[derive(Args)]
+struct GenerationArgs {
+    /// How large an input to generate, in the day's own terms (elves, moves, the side of a grid,
+    /// ...), instead of about the size of a real input
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Seed for the random number generator
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct ElvesArgs {
+    /// Only list this many of the elves carrying the most
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    top: Option<u64>,
+
+    /// How to print the elves
+    #[arg(long, value_enum, default_value_t = ElvesFormat::Table)]
+    format: ElvesFormat,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of day 1's input.txt
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct StrategyArgs {
+    /// The most rounds that may be lost
+    #[arg(long, value_name = "K")]
+    max_losses: Option<usize>,
+
+    /// The most rounds in a row the same move may be played
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    max_run: Option<u64>,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of day 2's input.txt
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RucksacksArgs {
+    /// How many elves share each badge
+    #[arg(long, default_value_t = day03::ELF_GROUP_SIZE as u64, value_parser = clap::value_parser!(u64).range(1..))]
+    group_size: u64,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of day 3's input.txt
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct AssignmentsArgs {
+    /// List the elves whose assignments overlap this elf's, numbered from 0 in the order they're
+    /// given, instead of how many there are for every elf
+    #[arg(long, value_name = "N")]
+    elf: Option<usize>,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of day 4's input.txt
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CratesArgs {
+    /// The model of crane carrying out the procedure
+    #[arg(long, value_enum, default_value_t = CraneModel::CrateMover9000)]
+    crane: CraneModel,
+
+    /// Lift at most this many crates at once, splitting bigger moves (only for the 9001, as the 9000
+    /// lifts one at a time anyway)
+    #[arg(long, value_name = "N")]
+    capacity: Option<NonZeroUsize>,
+
+    /// Only move crates between stacks next to each other
+    #[arg(long)]
+    adjacent_only: bool,
+
+    /// Only draw the stacks after this many steps, rather than after every step
+    #[arg(long, value_name = "N")]
+    step: Option<usize>,
+
+    /// Read the puzzle input from this file, or from stdin if "-", instead of day 5's input.txt
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CraneModel {
+    /// Moves crates one at a time
+    #[value(name = "9000")]
+    CrateMover9000,
+    /// Moves crates several at once
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ElvesFormat {
+    /// A readable table
+    Table,
+    /// CSV, with a header row
+    Csv,
+    /// One JSON object per line for each elf
+    Json,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct DaySelection {
+    /// The day to solve
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Solve every day
+    #[arg(long)]
+    all: bool,
+}
+
+impl DaySelection {
+    fn days(&self) -> Vec<u8> {
+        match self.day {
+            Some(day) => vec![day],
+            None => (1..=25).collect(),
+        }
+    }
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+fn read_day_input(day: u8, input: &Option<PathBuf>) -> Result<(PathBuf, String), String> {
+    let path = input.clone().unwrap_or_else(|| default_input_path(day));
+    let input = read_input_file(&path)
+        .map_err(|e| format!("Could not read input file {}: {}", path.display(), e))?;
+    Ok((path, input))
+}
+
+fn parse_param(arg: &str) -> Result<(String, i64), String> {
+    let (name, value) = arg.split_once('=').ok_or("expected NAME=VALUE")?;
+    let value = value.parse().map_err(|e| format!("invalid value for {}: {}", name, e))?;
+    Ok((name.to_string(), value))
+}
+
+// Checks that the puzzle takes every one of the params
+fn check_params(day: u8, puzzle: &dyn Puzzle, params: &Params) -> Result<(), String> {
+    let Some((name, _)) = params.iter().find(|(name, _)| !puzzle.params().contains(name)) else {
+        return Ok(());
+    };
+    if puzzle.params().is_empty() {
+        Err(format!("Day {} takes no params, so has no param {}", day, name))
+    } else {
+        Err(format!("Day {} has no param {}, only {}", day, name, puzzle.params().join(", ")))
+    }
+}
+
+fn describe_parse_error(error: ParseError, path: &Path) -> String {
+    let file = if path == Path::new("-") { PathBuf::from("<stdin>") } else { path.to_path_buf() };
+    error.in_file(file).to_string()
+}
+
+// Solves a single day, or returns None if it has none of the requested parts
+fn solve_day(day: u8, args: &RunArgs) -> Result<Option<DayReport>, String> {
+    let puzzle = days::puzzle(day).ok_or_else(|| format!("No solution for day {}", day))?;
+
+    let parts = puzzle.parts().iter()
+        .copied()
+        .filter(|part| args.part.is_none_or(|n| n == part.number()))
+        .collect::<Vec<Part>>();
+    if parts.is_empty() {
+        if args.days.all {
+            return Ok(None);
+        }
+        return Err(format!("Day {} has no part {}", day, args.part.unwrap()));
+    }
+
+    let params = args.params.iter().cloned().collect();
+    check_params(day, puzzle, &params)?;
+    let (path, input) = read_day_input(day, &args.input)?;
+    let start = Instant::now();
+    let answers = puzzle.solve(&input, &params, &parts).map_err(|e| describe_parse_error(e, &path))?;
+    Ok(Some(DayReport { day, variant: puzzle.variant(), answers, elapsed: start.elapsed() }))
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(args.threads.map_or(0, |n| n as usize))
+        .build()
+        .map_err(|e| format!("Could not start worker threads: {}", e))?;
+
+    // The days are solved concurrently, but reported in order once they've all finished
+    let reports = pool.install(|| {
+        args.days.days().into_par_iter()
+            .map(|day| solve_day(day, args))
+            .collect::<Vec<_>>()
+    });
+
+    for report in reports {
+        let Some(report) = report? else {
+            continue;
+        };
+        match args.format {
+            Format::Text => report.print_text(),
+            Format::Json => println!("{}", report.to_json()),
+        }
+    }
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let params = args.params.iter().cloned().collect();
+    let mut results = vec![];
+    for day in args.days.days() {
+        let variants = days::variants(day);
+        let variants = if args.variants { variants } else { &variants[..1] };
+
+        let (path, input) = read_day_input(day, &args.input)?;
+        for puzzle in variants {
+            check_params(day, *puzzle, &params)?;
+            // Report progress as we go, as the slower days can take a while
+            eprintln!("Benchmarking day {} ({})", day, puzzle.variant());
+            let timings = puzzle.bench(&input, &params, puzzle.parts(), args.iterations as usize)
+                .map_err(|e| describe_parse_error(e, &path))?;
+            results.extend(timings.into_iter().map(|(phase, samples)| BenchResult {
+                day,
+                variant: puzzle.variant(),
+                phase,
+                iterations: samples.len(),
+                stats: Stats::from_samples(&samples),
+            }));
+        }
+    }
+
+    match &args.csv {
+        None => print_table(&results),
+        Some(path) if path == Path::new("-") => {
+            write_csv(&mut stdout().lock(), &results).map_err(|e| format!("Could not write CSV: {}", e))?;
+        },
+        Some(path) => {
+            let file = File::create(path)
+                .map_err(|e| format!("Could not create CSV file {}: {}", path.display(), e))?;
+            let mut out = BufWriter::new(file);
+            write_csv(&mut out, &results)
+                .and_then(|_| out.flush())
+                .map_err(|e| format!("Could not write CSV file {}: {}", path.display(), e))?;
+        },
+    }
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let mut failures = 0;
+    for day in args.days.days() {
+        let answers_path = day_dir(day).join("answers.toml");
+        let answers = read_input_file(&answers_path)
+            .map_err(|e| format!("Could not read answers file {}: {}", answers_path.display(), e))
+            .and_then(|text| Answers::parse(&text)
+                .map_err(|e| format!("Could not parse answers file {}: {}", answers_path.display(), e)))?;
+
+        let variants = days::variants(day);
+        let variants = if args.variants { variants } else { &variants[..1] };
+        for puzzle in variants {
+            println!("Day {} ({})", day, puzzle.variant());
+            for (case, expected) in answers.cases() {
+                let path = day_dir(day).join(case.file_name());
+                let input = read_input_file(&path)
+                    .map_err(|e| format!("Could not read input file {}: {}", path.display(), e))?;
+                let parts = puzzle.parts().iter()
+                    .copied()
+                    .filter(|&part| expected.get(part).is_some())
+                    .collect::<Vec<Part>>();
+
+                check_params(day, *puzzle, expected.params())
+                    .map_err(|e| format!("{} in the {} case of {}", e, case, answers_path.display()))?;
+                let actual = match puzzle.solve(&input, expected.params(), &parts) {
+                    Ok(actual) => actual,
+                    Err(e) => {
+                        failures += 1;
+                        println!("  {}: FAIL\n{}", case, describe_parse_error(e, &path));
+                        continue;
+                    },
+                };
+                for (part, answer) in actual {
+                    match diff(expected.get(part).unwrap(), &answer) {
+                        None => println!("  {} part {}: ok", case, part.number()),
+                        Some(diff) => {
+                            failures += 1;
+                            println!("  {} part {}: FAIL", case, part.number());
+                            for line in diff.lines() {
+                                println!("    {}", line);
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} check(s) failed", failures));
+    }
+    Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let generator = generate::generator(args.day).ok_or_else(|| format!("No generator for day {}", args.day))?;
+    let size = args.generation.size.unwrap_or(generator.default_size);
+    print!("{}", generator.generate(size, args.generation.seed));
+    // The input is no use without the params it's meant to be solved with, but printing them to
+    // stdout would spoil it
+    let params = generator.params().iter()
+        .map(|(name, value)| format!("--param {}={}", name, value))
+        .collect::<Vec<_>>();
+    if !params.is_empty() {
+        eprintln!("Solve with {}", params.join(" "));
+    }
+    Ok(())
+}
+
+fn stress(args: &StressArgs) -> Result<(), String> {
+    let mut failures = 0;
+    for day in args.days.days() {
+        let generator = generate::generator(day).ok_or_else(|| format!("No generator for day {}", day))?;
+        let size = args.generation.size.unwrap_or(generator.default_size);
+        for seed in args.generation.seed..args.generation.seed + args.cases {
+            let input = generator.generate(size, seed);
+            match stress::cross_check(day, &input, &generator.params()) {
+                Ok(_) => println!("Day {} size {} seed {}: ok", day, size, seed),
+                Err(problem) => {
+                    failures += 1;
+                    println!("Day {} size {} seed {}: FAIL", day, size, seed);
+                    for line in problem.lines() {
+                        println!("    {}", line);
+                    }
+                },
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} input(s) failed; regenerate them with `aoc generate`", failures));
+    }
+    Ok(())
+}
+
+fn rank_elves(args: &ElvesArgs) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| default_input_path(1));
+    let input = open_input_file(&path)
+        .map_err(|e| format!("Could not open input file {}: {}", path.display(), e))?;
+    // Without a limit every elf is kept, in order
+    let top = args.top.map_or(usize::MAX, |n| n as usize);
+    let elves = day01::top_elves(input, top).map_err(|e| match e {
+        day01::Error::Parse(e) => describe_parse_error(e, &path),
+        day01::Error::Io(e) => format!("Could not read input file {}: {}", path.display(), e),
+    })?;
+    let elves = elves::rank(&elves);
+
+    match args.format {
+        ElvesFormat::Table => elves::print_table(&elves),
+        ElvesFormat::Csv => elves::write_csv(&mut stdout().lock(), &elves)
+            .map_err(|e| format!("Could not write CSV: {}", e))?,
+        ElvesFormat::Json => elves::write_json(&mut stdout().lock(), &elves)
+            .map_err(|e| format!("Could not write JSON: {}", e))?,
+    }
+    Ok(())
+}
+
+// Prints the best score, then the moves as a guide that scores it when read as moves
+fn plan_strategy(args: &StrategyArgs) -> Result<(), String> {
+    use day02::strategy::{best_plan, Constraints};
+    use day02::RoundResult;
+
+    let (path, input) = read_day_input(2, &args.input)?;
+    let guide = day02::parse_guide(&input).map_err(|e| describe_parse_error(e, &path))?;
+    let opponent = guide.into_iter().map(|(opp_move, _)| opp_move).collect::<Vec<_>>();
+    let constraints = Constraints { max_losses: args.max_losses, max_run: args.max_run.map(|n| n as usize) };
+    let plan = best_plan(&opponent, &constraints).ok_or("No moves meet the constraints")?;
+
+    let results = plan.results(&opponent);
+    let count = |result: RoundResult| results.iter().filter(|&&r| r == result).count();
+    println!(
+        "Score: {} ({} wins, {} draws, {} losses)",
+        plan.score, count(RoundResult::Win), count(RoundResult::Draw), count(RoundResult::Loss),
+    );
+    for (theirs, mine) in opponent.iter().zip(&plan.moves) {
+        println!("{} {}", theirs.opponent_code(), mine.player_code());
+    }
+    Ok(())
+}
+
+fn diagnose_rucksacks(args: &RucksacksArgs) -> Result<(), String> {
+    let (_, input) = read_day_input(3, &args.input)?;
+    println!("{}", day03::diagnose::diagnose(&input, args.group_size as usize));
+    Ok(())
+}
+
+fn analyse_assignments(args: &AssignmentsArgs) -> Result<(), String> {
+    let (path, input) = read_day_input(4, &args.input)?;
+    let range_pairs = day04::parse_range_pairs(&input).map_err(|e| describe_parse_error(e, &path))?;
+    let assignments = day04::all_assignments(&range_pairs);
+    if args.elf.is_some_and(|elf| elf >= assignments.len()) {
+        return Err(format!("There are only {} elves", assignments.len()));
+    }
+    let sweep = day04::sweep::sweep(&assignments);
+
+    let describe = |sections: &day04::SectionSet<u64>| {
+        if sections.is_empty() { "none".to_string() } else { sections.to_string() }
+    };
+    println!("Most elves on one section: {}", sweep.peak);
+    println!("Sections with that many: {}", describe(&sweep.peak_sections));
+    println!("Sections with no elf: {}", describe(&sweep.sections_with(0)));
+    println!("Sections with one elf: {}", describe(&sweep.sections_with(1)));
+    match args.elf {
+        Some(elf) => {
+            let others = day04::sweep::overlapping(&assignments, elf);
+            let others = others.iter().map(|other| other.to_string()).collect::<Vec<_>>();
+            println!("Elf {} (line {}) overlaps: {}", elf, elf / 2 + 1, others.join(", "));
+        },
+        None => for (elf, count) in sweep.overlap_counts.iter().enumerate() {
+            println!("Elf {} (line {}) overlaps {} other(s)", elf, elf / 2 + 1, count);
+        },
+    }
+    Ok(())
+}
+
+fn replay_crates(args: &CratesArgs) -> Result<(), String> {
+    use day05::crane::{AdjacentCrane, Crane, CrateMover9000, CrateMover9001, LimitedCrane};
+
+    let crane: Box<dyn Crane> = match (args.crane, args.capacity) {
+        (CraneModel::CrateMover9000, None) => Box::new(CrateMover9000),
+        (CraneModel::CrateMover9000, Some(_)) => {
+            return Err("The CrateMover 9000 lifts one crate at a time, so has no capacity to set".to_string());
+        },
+        (CraneModel::CrateMover9001, None) => Box::new(CrateMover9001),
+        (CraneModel::CrateMover9001, Some(capacity)) => Box::new(LimitedCrane { capacity }),
+    };
+    let crane: Box<dyn Crane> = if args.adjacent_only { Box::new(AdjacentCrane(crane)) } else { crane };
+
+    let (path, input) = read_day_input(5, &args.input)?;
+    let (stacks, proc_steps) = day05::parse_input(&input).map_err(|e| describe_parse_error(e, &path))?;
+    replay_with(crane, stacks, &proc_steps, args.step)
+}
+
+fn replay_with(
+    crane: impl day05::crane::Crane,
+    stacks: day05::Stacks,
+    proc_steps: &[day05::ProcStep],
+    step: Option<usize>,
+) -> Result<(), String> {
+    let mut replay = day05::replay::Replay::new(crane, stacks, proc_steps);
+    match step {
+        Some(step) if step > proc_steps.len() => {
+            Err(format!("The procedure only has {} steps", proc_steps.len()))
+        },
+        Some(step) => {
+            replay.seek(step).map_err(|e| format!("Stopped at {}", e))?;
+            print!("{}", replay.render());
+            Ok(())
+        },
+        None => {
+            print!("{}", replay.history());
+            Ok(())
+        },
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Generate(args) => generate(args),
+        Command::Stress(args) => stress(args),
+        Command::Elves(args) => rank_elves(args),
+        Command::Strategy(args) => plan_strategy(args),
+        Command::Rucksacks(args) => diagnose_rucksacks(args),
+        Command::Assignments(args) => analyse_assignments(args),
+        Command::Crates(args) => replay_crates(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+use serde::{Serialize, Serializer};
+use crate::solver::Part;
+
+// The answers the run command found for a single day
+#[derive(Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub variant: &'static str,
+    #[serde(serialize_with = "serialize_answers")]
+    pub answers: Vec<(Part, String)>,
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Duration,
+}
+
+impl DayReport {
+    pub fn print_text(&self) {
+        println!("Day {}", self.day);
+        for (part, answer) in &self.answers {
+            let answer = answer.trim_end();
+            if answer.contains('\n') {
+                println!("  Part {}:\n{}", part.number(), answer);
+            } else {
+                println!("  Part {}: {}", part.number(), answer);
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("A report can always be serialised")
+    }
+}
+
+// Answers are keyed by part ("part1", "part2"), with any trailing newline of a drawn answer removed
+fn serialize_answers<S: Serializer>(answers: &[(Part, String)], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(answers.iter().map(|(part, answer)| (format!("part{}", part.number()), answer.trim_end())))
+}
+
+fn serialize_nanos<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(elapsed.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::solver::Part;
+    use super::DayReport;
+
+    #[test]
+    fn serialises_to_json() {
+        let report = DayReport {
+            day: 10,
+            variant: "default",
+            answers: vec![(Part::One, "13140".to_string()), (Part::Two, "#.\n.#\n".to_string())],
+            elapsed: Duration::from_micros(1500),
+        };
+        assert_eq!(
+            report.to_json(),
+            r##"{"day":10,"variant":"default","answers":{"part1":"13140","part2":"#.\n.#"},"elapsed_ns":1500000}"##
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use serde::Deserialize;
+use aoc_common::ParseError;
+use crate::bench::Phase;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+// Settings for a puzzle that aren't in its input, by name, such as the row day 15 asks about. Any
+// that aren't given are the real puzzle's.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.0.get(name).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.0.iter().map(|(name, &value)| (name.as_str(), value))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, i64)> for Params {
+    fn from_iter<I: IntoIterator<Item = (S, i64)>>(iter: I) -> Params {
+        Params(iter.into_iter().map(|(name, value)| (name.into(), value)).collect())
+    }
+}
+
+// A single day's puzzle: parse the input once, then answer each part from the parsed form
+pub trait Solver: Sync {
+    type Input<'a>;
+
+    // Day 25 only has a single part
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+    // Distinguishes between alternative implementations of the same day
+    const VARIANT: &'static str = "default";
+    // The names of the params the puzzle takes
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+    // Only puzzles that take params need to look at them
+    fn parse_with<'a>(&self, input: &'a str, _params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        self.parse(input)
+    }
+    fn part1(&self, input: &Self::Input<'_>) -> impl Display;
+    fn part2(&self, input: &Self::Input<'_>) -> impl Display;
+}
+
+// Object-safe view of a Solver, so days with different parsed input types can share a registry
+pub trait Puzzle: Sync {
+    fn parts(&self) -> &'static [Part];
+    fn variant(&self) -> &'static str;
+    fn params(&self) -> &'static [&'static str];
+    fn solve(&self, input: &str, params: &Params, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError>;
+    // Times each phase (parsing, then each of the parts) separately, the given number of times
+    fn bench(
+        &self,
+        input: &str,
+        params: &Params,
+        parts: &[Part],
+        iterations: usize,
+    ) -> Result<Vec<(Phase, Vec<Duration>)>, ParseError>;
+}
+
+impl<S: Solver> Puzzle for S {
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn variant(&self) -> &'static str {
+        S::VARIANT
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        S::PARAMS
+    }
+
+    fn solve(&self, input: &str, params: &Params, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError> {
+        let parsed = self.parse_with(input, params)?;
+        let answers = parts.iter()
+            .map(|&part| {
+                let answer = match part {
+                    Part::One => self.part1(&parsed).to_string(),
+                    Part::Two => self.part2(&parsed).to_string(),
+                };
+                (part, answer)
+            })
+            .collect();
+        Ok(answers)
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        params: &Params,
+        parts: &[Part],
+        iterations: usize,
+    ) -> Result<Vec<(Phase, Vec<Duration>)>, ParseError> {
+        let mut parse_times = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let parsed = black_box(self.parse_with(black_box(input), params)?);
+            parse_times.push(start.elapsed());
+            drop(parsed);
+        }
+        let mut timings = vec![(Phase::Parse, parse_times)];
+
+        let parsed = self.parse_with(input, params)?;
+        for &part in parts {
+            let part_times = (0..iterations)
+                .map(|_| {
+                    let start = Instant::now();
+                    match part {
+                        Part::One => black_box(self.part1(black_box(&parsed)).to_string()),
+                        Part::Two => black_box(self.part2(black_box(&parsed)).to_string()),
+                    };
+                    start.elapsed()
+                })
+                .collect();
+            timings.push((Phase::Part(part), part_times));
+        }
+        Ok(timings)
+    }
+}
@@ -0,0 +1,75 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use crate::days;
+use crate::solver::{Params, Part};
+use crate::verify::diff;
+
+// Solves an input with every implementation of a day, checking that none of them fail and that
+// they all agree on the answers
+pub fn cross_check(day: u8, input: &str, params: &Params) -> Result<Vec<(Part, String)>, String> {
+    let mut expected: Option<(&str, Vec<(Part, String)>)> = None;
+    for puzzle in days::variants(day) {
+        let variant = puzzle.variant();
+        let answers = catch_unwind(AssertUnwindSafe(|| puzzle.solve(input, params, puzzle.parts())))
+            .map_err(|panic| format!("{} panicked: {}", variant, panic_message(panic.as_ref())))?
+            .map_err(|e| format!("{} could not parse the input:\n{}", variant, e))?;
+
+        match &expected {
+            None => expected = Some((variant, answers)),
+            Some((expected_variant, expected_answers)) => {
+                for ((part, expected_answer), (_, answer)) in expected_answers.iter().zip(&answers) {
+                    if let Some(diff) = diff(expected_answer, answer) {
+                        return Err(format!(
+                            "{} and {} disagree on part {}:\n{}",
+                            expected_variant, variant, part.number(), diff,
+                        ));
+                    }
+                }
+            },
+        }
+    }
+    expected.map(|(_, answers)| answers).ok_or_else(|| format!("No solution for day {}", day))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(|s| s.as_str()))
+        .unwrap_or("(no message)")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::generator;
+    use crate::solver::Params;
+    use super::cross_check;
+
+    #[test]
+    fn solves_small_generated_inputs() {
+        for day in 1..=25 {
+            for seed in 0..3 {
+                let generator = generator(day).unwrap();
+                let input = generator.generate(10, seed);
+                if let Err(problem) = cross_check(day, &input, &generator.params()) {
+                    panic!("Day {} with seed {}: {}\n{}", day, seed, problem, input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day06_variants_read_only_the_first_line() {
+        let answers = cross_check(6, "abca\nd\n", &Params::default()).unwrap();
+        assert!(answers.iter().all(|(_, answer)| answer == "no marker"));
+    }
+
+    #[test]
+    fn day15_reports_no_uncovered_position() {
+        let answers = cross_check(15, "", &Params::default()).unwrap();
+        assert_eq!(answers[1].1, "no uncovered position");
+
+        // A single sensor that sees the whole search area
+        let params = [("row", 10), ("search-max", 20)].into_iter().collect();
+        let answers = cross_check(15, "Sensor at x=0, y=0: closest beacon is at x=40, y=0\n", &params).unwrap();
+        assert_eq!(answers[1].1, "no uncovered position");
+    }
+}
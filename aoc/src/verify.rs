@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter};
+use serde::Deserialize;
+use crate::solver::{Params, Part};
+
+// The known answers for a day, as recorded in its answers.toml
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    input: Option<CaseAnswers>,
+    example: Option<CaseAnswers>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CaseAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+    // Any the puzzle needs to solve this case, such as the smaller area day 15's example asks about
+    #[serde(default)]
+    params: Params,
+}
+
+// Which of a day's input files a set of answers belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    Input,
+    Example,
+}
+
+impl Case {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Case::Input => "input.txt",
+            Case::Example => "example.txt",
+        }
+    }
+}
+
+impl Display for Case {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Case::Input => write!(f, "input"),
+            Case::Example => write!(f, "example"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    pub fn cases(&self) -> Vec<(Case, &CaseAnswers)> {
+        [(Case::Input, &self.input), (Case::Example, &self.example)].into_iter()
+            .filter_map(|(case, answers)| answers.as_ref().map(|a| (case, a)))
+            .collect()
+    }
+}
+
+impl CaseAnswers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+}
+
+// A line-by-line comparison of an answer with the expected one, or None if they match. Trailing
+// whitespace is ignored, as multi-line answers are awkward to write down without a final newline.
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    let expected = expected.trim_end();
+    let actual = actual.trim_end();
+    if expected == actual {
+        return None;
+    }
+
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    let mut out = vec![];
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    out.push(format!("+ {}", a));
+                }
+            },
+        }
+    }
+    Some(out.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::{Params, Part};
+    use super::{diff, Answers, Case};
+
+    #[test]
+    fn reads_answers() {
+        let answers = Answers::parse("[input]\npart1 = \"12\"\n\n[example]\npart1 = \"3\"\npart2 = '''\n#.\n.#\n'''\n").unwrap();
+        let cases = answers.cases();
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].0, Case::Input);
+        assert_eq!(cases[0].1.get(Part::One), Some("12"));
+        assert_eq!(cases[0].1.get(Part::Two), None);
+        assert_eq!(cases[1].1.get(Part::Two), Some("#.\n.#\n"));
+        assert_eq!(cases[1].1.params(), &Params::default());
+
+        let answers = Answers::parse("[example]\npart1 = \"26\"\n\n[example.params]\nrow = 10\n").unwrap();
+        assert_eq!(answers.cases()[0].1.params().get("row"), Some(10));
+    }
+
+    #[test]
+    fn diffs_differing_lines() {
+        assert_eq!(diff("#.\n.#\n", "#.\n.#"), None);
+        assert_eq!(diff("45000", "41000"), Some("- 45000\n+ 41000".to_string()));
+        assert_eq!(diff("#.\n.#", "#.\n##\n.."), Some("  #.\n- .#\n+ ##\n+ ..".to_string()));
+    }
+}
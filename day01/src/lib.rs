@@ -0,0 +1,250 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+use aoc_common::parse::number;
+use aoc_common::ParseError;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "could not read input: {}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+// The items carried by a single elf. The index counts elves from 0 in the order they're listed, and
+// the lines (numbered from 1) are those of the first and last of its items.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElfStats {
+    pub index: usize,
+    pub first_line: usize,
+    pub last_line: usize,
+    pub count: usize,
+    pub total: u64,
+    pub min: u64,
+    pub max: u64,
+    pub median: f64,
+}
+
+impl ElfStats {
+    fn from_items(index: usize, first_line: usize, last_line: usize, items: &mut [u64]) -> ElfStats {
+        items.sort_unstable();
+        let count = items.len();
+        let median = if count % 2 == 1 {
+            items[count / 2] as f64
+        } else {
+            (items[count / 2 - 1] + items[count / 2]) as f64 / 2.0
+        };
+        ElfStats {
+            index,
+            first_line,
+            last_line,
+            count,
+            total: items.iter().sum(),
+            min: items[0],
+            max: items[count - 1],
+            median,
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.total as f64 / self.count as f64
+    }
+}
+
+// Reads the elves one at a time, so only a single elf's items are held in memory at once
+pub struct Elves<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    index: usize,
+    first_line: usize,
+    items: Vec<u64>,
+}
+
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves { reader, line: String::new(), line_number: 0, index: 0, first_line: 0, items: vec![] }
+}
+
+impl<R> Elves<R> {
+    // Ends the current group, if it has any items. Runs of blank lines don't make empty elves.
+    fn finish_elf(&mut self) -> Option<ElfStats> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let last_line = self.first_line + self.items.len() - 1;
+        let elf = ElfStats::from_items(self.index, self.first_line, last_line, &mut self.items);
+        self.index += 1;
+        self.items.clear();
+        Some(elf)
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<ElfStats, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                // The last elf needn't be followed by a blank line
+                Ok(0) => return self.finish_elf().map(Ok),
+                Ok(_) => self.line_number += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+
+            let line = self.line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                if let Some(elf) = self.finish_elf() {
+                    return Some(Ok(elf));
+                }
+                continue;
+            }
+            match number(line, line) {
+                Ok(calories) => {
+                    if self.items.is_empty() {
+                        self.first_line = self.line_number;
+                    }
+                    self.items.push(calories);
+                },
+                Err(mut e) => {
+                    // Only the line was parsed, so the error needs its position in the whole input
+                    e.line = self.line_number;
+                    return Some(Err(Error::Parse(e)));
+                },
+            }
+        }
+    }
+}
+
+// Orders elves by their total, breaking ties in favour of the earlier elf
+struct Ranked(ElfStats);
+
+impl Ranked {
+    fn key(&self) -> (u64, Reverse<usize>) {
+        (self.0.total, Reverse(self.0.index))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+// The k elves carrying the most calories seen so far, kept in a min-heap so that each new elf only
+// has to be compared with the weakest of them
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<Ranked>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> TopK {
+        TopK { k, heap: BinaryHeap::new() }
+    }
+
+    pub fn push(&mut self, elf: ElfStats) {
+        let elf = Reverse(Ranked(elf));
+        if self.heap.len() < self.k {
+            self.heap.push(elf);
+        } else if let Some(mut weakest) = self.heap.peek_mut() {
+            if elf < *weakest {
+                *weakest = elf;
+            }
+        }
+    }
+
+    // The elves, carrying the most first
+    pub fn into_sorted_vec(self) -> Vec<ElfStats> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(Ranked(elf))| elf).collect()
+    }
+}
+
+// The k elves carrying the most calories, carrying the most first
+pub fn top_elves<R: BufRead>(reader: R, k: usize) -> Result<Vec<ElfStats>, Error> {
+    let mut top = TopK::new(k);
+    for elf in elves(reader) {
+        top.push(elf?);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+// The combined calories carried by the first n of the given elves
+pub fn top_total(elves: &[ElfStats], n: usize) -> u64 {
+    elves.iter().take(n).map(|elf| elf.total).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(top_total(&top_elves(EXAMPLE.as_bytes(), 1).unwrap(), 1), 24000);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(top_total(&top_elves(EXAMPLE.as_bytes(), 3).unwrap(), 3), 45000);
+    }
+
+    #[test]
+    fn reports_stats_per_elf() {
+        let stats = elves(EXAMPLE.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(stats.len(), 5);
+        assert_eq!(stats[3], ElfStats {
+            index: 3, first_line: 10, last_line: 12, count: 3, total: 24000, min: 7000, max: 9000, median: 8000.0,
+        });
+        assert_eq!(stats[3].mean(), 8000.0);
+        assert_eq!(stats[4].median, 10000.0);
+        assert_eq!(stats[0].median, 2000.0);
+    }
+
+    #[test]
+    fn keeps_earliest_of_tied_elves() {
+        let top = top_elves("5\n\n7\n\n2\n3\n\n7\n".as_bytes(), 3).unwrap();
+        assert_eq!(top.iter().map(|elf| elf.index).collect::<Vec<_>>(), vec![1, 3, 0]);
+        assert_eq!(top_elves("1\n".as_bytes(), 0).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn locates_bad_calories() {
+        let Err(Error::Parse(e)) = top_elves("1\n\n2\nx3\n".as_bytes(), 3) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (4, 1));
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
+use serde::Deserialize;
+use aoc_common::ParseError;
+use aoc_common::parse::until;
+use crate::RoundResult;
+
+// The result for the move at index mine against the move at index theirs, in a cyclic game of size
+// moves. Each move beats the (size - 1) / 2 moves before it in the cycle, and loses to those after.
+pub fn cyclic_result(size: usize, mine: usize, theirs: usize) -> RoundResult {
+    let distance = (mine + size - theirs) % size;
+    if distance == 0 {
+        RoundResult::Draw
+    } else if distance <= (size - 1) / 2 {
+        RoundResult::Win
+    } else {
+        RoundResult::Loss
+    }
+}
+
+// The index of a move giving result against the move at index theirs, in a cyclic game of size moves
+pub fn cyclic_required_move(size: usize, result: &RoundResult, theirs: usize) -> usize {
+    match result {
+        RoundResult::Win => (theirs + 1) % size,
+        RoundResult::Draw => theirs,
+        RoundResult::Loss => (theirs + size - 1) % size,
+    }
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesError::Toml(e) => write!(f, "{}", e),
+            RulesError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Move {
+    pub name: String,
+    pub value: u32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Outcomes {
+    pub win: u32,
+    pub draw: u32,
+    pub loss: u32,
+}
+
+// What a code in the second column of a strategy guide tells the player to do: play a move, get an
+// outcome, or play the move the given number of places after the opponent's in the cycle
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "lowercase", deny_unknown_fields)]
+pub enum Meaning {
+    Move(String),
+    Outcome(RoundResult),
+    Shift(i64),
+}
+
+// One way of reading a strategy guide: the move each code in the first column stands for, and the
+// meaning of each code in the second
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Interpretation {
+    pub name: String,
+    pub first: BTreeMap<String, String>,
+    pub second: BTreeMap<String, Meaning>,
+}
+
+impl Interpretation {
+    // Each round of the guide as the indexes of the opponent's move and the player's
+    pub fn decode(&self, game: &Game, input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        input.lines().map(|line| {
+            let (first, second) = until(input, line, " ")?;
+            let theirs = self.first.get(first)
+                .map(|name| game.move_index(name))
+                .ok_or_else(|| ParseError::at(input, first, one_of(self.first.keys())))?;
+            let mine = match self.second.get(second) {
+                Some(Meaning::Move(name)) => game.move_index(name),
+                Some(Meaning::Outcome(result)) => game.required_move(result, theirs),
+                Some(Meaning::Shift(shift)) => {
+                    let size = game.moves.len() as i64;
+                    (theirs as i64 + shift).rem_euclid(size) as usize
+                },
+                None => return Err(ParseError::at(input, second, one_of(self.second.keys()))),
+            };
+            Ok((theirs, mine))
+        }).collect()
+    }
+}
+
+fn one_of<'a>(codes: impl Iterator<Item = &'a String>) -> String {
+    let codes = codes.map(|c| c.as_str()).collect::<Vec<_>>();
+    match codes.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("one of {} or {}", rest.join(", "), last),
+        _ => codes.join(""),
+    }
+}
+
+// A cyclic game, as described by a rules file: its moves listed in cyclic order (so that each beats
+// the half of the others listed just before it, wrapping around), the scores for each outcome, and
+// the ways a strategy guide for it can be read
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Game {
+    pub moves: Vec<Move>,
+    pub outcomes: Outcomes,
+    #[serde(default)]
+    pub interpretations: Vec<Interpretation>,
+}
+
+impl Game {
+    pub fn rock_paper_scissors() -> Game {
+        Game::parse(include_str!("../rps.toml")).expect("The built in rules are valid")
+    }
+
+    pub fn parse(rules: &str) -> Result<Game, RulesError> {
+        let game: Game = toml::from_str(rules).map_err(RulesError::Toml)?;
+        game.check()?;
+        Ok(game)
+    }
+
+    fn check(&self) -> Result<(), RulesError> {
+        let invalid = |message: String| Err(RulesError::Invalid(message));
+        if self.moves.len().is_multiple_of(2) {
+            return invalid(format!("a cyclic game needs an odd number of moves, but there are {}", self.moves.len()));
+        }
+        let mut names = HashSet::new();
+        if let Some(name) = self.moves.iter().map(|m| &m.name).find(|&name| !names.insert(name)) {
+            return invalid(format!("move {:?} is listed more than once", name));
+        }
+
+        for interpretation in &self.interpretations {
+            let mut codes = interpretation.first.keys().chain(interpretation.second.keys());
+            if let Some(code) = codes.find(|code| code.is_empty() || code.contains(char::is_whitespace)) {
+                return invalid(format!("code {:?} in {:?} is empty or contains spaces", code, interpretation.name));
+            }
+            let moves = interpretation.first.values()
+                .chain(interpretation.second.values().filter_map(|meaning| match meaning {
+                    Meaning::Move(name) => Some(name),
+                    _ => None,
+                }));
+            for name in moves {
+                if !names.contains(name) {
+                    return invalid(format!("{:?} uses move {:?}, which isn't in the game", interpretation.name, name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The index of the named move, which must have been checked to be in the game
+    fn move_index(&self, name: &str) -> usize {
+        self.moves.iter().position(|m| m.name == name).expect("Checked by Game::check")
+    }
+
+    pub fn result(&self, mine: usize, theirs: usize) -> RoundResult {
+        cyclic_result(self.moves.len(), mine, theirs)
+    }
+
+    pub fn required_move(&self, result: &RoundResult, theirs: usize) -> usize {
+        cyclic_required_move(self.moves.len(), result, theirs)
+    }
+
+    pub fn outcome_score(&self, result: &RoundResult) -> u32 {
+        match result {
+            RoundResult::Win => self.outcomes.win,
+            RoundResult::Draw => self.outcomes.draw,
+            RoundResult::Loss => self.outcomes.loss,
+        }
+    }
+
+    pub fn score(&self, mine: usize, theirs: usize) -> u32 {
+        self.moves[mine].value + self.outcome_score(&self.result(mine, theirs))
+    }
+
+    // The total score of rounds decoded by an interpretation
+    pub fn total_score(&self, rounds: &[(usize, usize)]) -> u32 {
+        rounds.iter().map(|&(theirs, mine)| self.score(mine, theirs)).sum()
+    }
+
+    // The total score of the guide under each of the game's interpretations, or why the guide can't
+    // be read that way
+    pub fn scores(&self, input: &str) -> Vec<(&str, Result<u32, ParseError>)> {
+        self.interpretations.iter()
+            .map(|i| (i.name.as_str(), i.decode(self, input).map(|rounds| self.total_score(&rounds))))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RoundResult::{Draw, Loss, Win};
+    use super::Game;
+
+    const RPSLS: &str = include_str!("../rpsls.toml");
+
+    #[test]
+    fn plays_rock_paper_scissors_lizard_spock() {
+        let game = Game::parse(RPSLS).unwrap();
+        let index = |name: &str| game.move_index(name);
+        let beats = [
+            ("Scissors", "Paper"), ("Paper", "Rock"), ("Rock", "Lizard"), ("Lizard", "Spock"),
+            ("Spock", "Scissors"), ("Scissors", "Lizard"), ("Lizard", "Paper"), ("Paper", "Spock"),
+            ("Spock", "Rock"), ("Rock", "Scissors"),
+        ];
+        for (winner, loser) in beats {
+            assert_eq!(game.result(index(winner), index(loser)), Win, "{} beats {}", winner, loser);
+            assert_eq!(game.result(index(loser), index(winner)), Loss, "{} loses to {}", loser, winner);
+        }
+        for mine in 0..5 {
+            for theirs in 0..5 {
+                let result = game.result(mine, theirs);
+                assert_eq!(game.result(game.required_move(&result, theirs), theirs), result);
+            }
+            assert_eq!(game.result(mine, mine), Draw);
+        }
+    }
+
+    #[test]
+    fn scores_each_interpretation() {
+        let game = Game::parse(RPSLS).unwrap();
+
+        // Spock beats rock, and scissors draws with scissors. W isn't an outcome.
+        let scores = game.scores("A W\nC Z\n");
+        assert_eq!(scores[0], ("moves", Ok(5 + 6 + 3 + 3)));
+        assert_eq!(scores[1].0, "outcomes");
+        let error = scores[1].1.as_ref().unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 3, "one of X, Y or Z"));
+
+        // Scissors beats lizard, and rock loses to Spock, whether read as moves, outcomes or shifts
+        let scores = game.scores("D Z\nE X\n");
+        assert_eq!(scores, vec![("moves", Ok(3 + 6 + 1)), ("outcomes", Ok(3 + 6 + 1)), ("shifts", Ok(3 + 6 + 1))]);
+    }
+
+    #[test]
+    fn rejects_bad_rules() {
+        let rules = &RPSLS[..RPSLS.find("[[moves]]\nname = \"Scissors\"").unwrap()];
+        let error = Game::parse(rules).unwrap_err();
+        assert_eq!(error.to_string(), "a cyclic game needs an odd number of moves, but there are 4");
+
+        let rules = RPSLS.replace("Y = { move = \"Paper\" }", "Y = { move = \"Pen\" }");
+        let error = Game::parse(&rules).unwrap_err();
+        assert_eq!(error.to_string(), "\"moves\" uses move \"Pen\", which isn't in the game");
+    }
+}
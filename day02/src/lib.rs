@@ -0,0 +1,143 @@
+use serde::Deserialize;
+use aoc_common::ParseError;
+use aoc_common::parse::until;
+use crate::game::{cyclic_required_move, cyclic_result};
+use crate::RoundResult::{Draw, Loss, Win};
+use crate::RPS::{Rock, Paper, Scissors};
+
+pub mod game;
+pub mod strategy;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RPS {
+    Rock,
+    Paper,
+    Scissors
+}
+
+impl RPS {
+    pub fn parse(s: &str) -> Result<RPS, ParseError> {
+        match s {
+            "A" => Ok(Rock),
+            "B" => Ok(Paper),
+            "C" => Ok(Scissors),
+            "X" => Ok(Rock),
+            "Y" => Ok(Paper),
+            "Z" => Ok(Scissors),
+            _ => Err(ParseError::at(s, s, "one of A, B, C, X, Y or Z"))
+        }
+    }
+
+    // The codes for the move in each column of the guide, when read as moves
+    pub fn opponent_code(&self) -> &'static str {
+        ["A", "B", "C"][self.index()]
+    }
+
+    pub fn player_code(&self) -> &'static str {
+        ["X", "Y", "Z"][self.index()]
+    }
+
+    pub fn value(&self) -> u32 {
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+
+    // The moves in cyclic order, each beating the one before it
+    const CYCLE: [RPS; 3] = [Rock, Paper, Scissors];
+
+    fn index(&self) -> usize {
+        RPS::CYCLE.iter().position(|m| m == self).unwrap()
+    }
+
+    pub fn result(&self, opponent: &RPS) -> RoundResult {
+        cyclic_result(RPS::CYCLE.len(), self.index(), opponent.index())
+    }
+
+    pub fn score(&self, opponent: &RPS) -> u32 {
+        self.value() + self.result(opponent).value()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundResult {
+    Win,
+    Draw,
+    Loss
+}
+
+impl RoundResult {
+    pub fn parse(s: &str) -> Result<RoundResult, ParseError> {
+        match s {
+            "X" => Ok(Loss),
+            "Y" => Ok(Draw),
+            "Z" => Ok(Win),
+            _ => Err(ParseError::at(s, s, "one of X, Y or Z"))
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        match self {
+            Win => 6,
+            Draw => 3,
+            Loss => 0,
+        }
+    }
+
+    pub fn required_move(&self, opponent: &RPS) -> RPS {
+        RPS::CYCLE[cyclic_required_move(RPS::CYCLE.len(), self, opponent.index())]
+    }
+}
+
+// The second column is only interpreted when scoring, but is checked to be one of X, Y or Z here
+pub fn parse_guide(input: &str) -> Result<Vec<(RPS, &str)>, ParseError> {
+    input.lines().map(|line| {
+        let (abc, xyz) = until(input, line, " ")?;
+        let opp_move = RPS::parse(abc).map_err(|e| e.within(input, abc))?;
+        RoundResult::parse(xyz).map_err(|e| e.within(input, xyz))?;
+        Ok((opp_move, xyz))
+    }).collect()
+}
+
+pub fn total_score_as_moves(guide: &[(RPS, &str)]) -> u32 {
+    guide.iter()
+        .map(|(opp_move, xyz)| RPS::parse(xyz).expect("Checked by parse_guide").score(opp_move))
+        .sum()
+}
+
+pub fn total_score_as_results(guide: &[(RPS, &str)]) -> u32 {
+    guide.iter()
+        .map(|(opp_move, xyz)| {
+            RoundResult::parse(xyz).expect("Checked by parse_guide").required_move(opp_move).score(opp_move)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let guide = parse_guide(EXAMPLE).unwrap();
+        assert_eq!(total_score_as_moves(&guide), 15);
+    }
+
+    #[test]
+    fn example_part2() {
+        let guide = parse_guide(EXAMPLE).unwrap();
+        assert_eq!(total_score_as_results(&guide), 12);
+    }
+
+    #[test]
+    fn built_in_rules_match_puzzle() {
+        let game = game::Game::rock_paper_scissors();
+        let scores = game.scores(EXAMPLE);
+        assert_eq!(scores, vec![("moves", Ok(15)), ("outcomes", Ok(12)), ("shifts", Ok(8 + 5 + 2))]);
+    }
+}
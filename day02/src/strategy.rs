@@ -0,0 +1,172 @@
+use crate::{RoundResult, RPS};
+
+// Limits on the moves the player may choose
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Constraints {
+    // The most rounds that may be lost
+    pub max_losses: Option<usize>,
+    // The most consecutive rounds the same move may be played in
+    pub max_run: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct Plan {
+    pub score: u32,
+    pub moves: Vec<RPS>,
+}
+
+impl Plan {
+    pub fn results(&self, opponent: &[RPS]) -> Vec<RoundResult> {
+        self.moves.iter().zip(opponent).map(|(mine, theirs)| mine.result(theirs)).collect()
+    }
+}
+
+// The state after a round: how many rounds have been lost so far (only tracked when losses are
+// limited), the move just played, and how many rounds in a row it's been played (only tracked when
+// runs are limited)
+type State = (usize, usize, usize);
+
+// Numbers each of the states the constraints need tracking
+struct States {
+    losses: usize,
+    runs: usize,
+}
+
+impl States {
+    fn index(&self, losses: usize, mine: usize, run: usize) -> usize {
+        (losses * RPS::CYCLE.len() + mine) * self.runs + run - 1
+    }
+
+    fn len(&self) -> usize {
+        self.losses * RPS::CYCLE.len() * self.runs
+    }
+}
+
+// The highest scoring moves against the opponent's moves within the constraints, or None if there
+// are none. This is a dynamic programme over the rounds, so takes time and space proportional to
+// the number of rounds times the number of states the constraints need tracking.
+pub fn best_plan(opponent: &[RPS], constraints: &Constraints) -> Option<Plan> {
+    if constraints.max_run == Some(0) {
+        return None;
+    }
+    // Losses or runs beyond the number of rounds can't happen, so needn't be told apart
+    let max_losses = constraints.max_losses.filter(|&k| k < opponent.len());
+    let max_run = constraints.max_run.filter(|&n| n < opponent.len());
+    let states = States {
+        losses: max_losses.map_or(1, |k| k + 1),
+        runs: max_run.unwrap_or(1),
+    };
+
+    // The best score to reach each state after each round, and the state before it on the way
+    let mut scores: Vec<Option<u32>> = vec![None; states.len()];
+    let mut previous: Vec<Vec<usize>> = Vec::with_capacity(opponent.len());
+    for (round, theirs) in opponent.iter().enumerate() {
+        let mut next_scores = vec![None; states.len()];
+        let mut next_previous = vec![usize::MAX; states.len()];
+
+        // Before the first round there's a single starting state, with nothing played yet
+        let starts: Vec<(Option<State>, u32)> = if round == 0 {
+            vec![(None, 0)]
+        } else {
+            let mut starts = vec![];
+            for losses in 0..states.losses {
+                for last in 0..RPS::CYCLE.len() {
+                    for run in 1..=states.runs {
+                        if let Some(score) = scores[states.index(losses, last, run)] {
+                            starts.push((Some((losses, last, run)), score));
+                        }
+                    }
+                }
+            }
+            starts
+        };
+
+        for (start, score) in starts {
+            for (mine, mine_move) in RPS::CYCLE.iter().enumerate() {
+                let (losses, run) = match start {
+                    None => (0, 1),
+                    Some((losses, last, run)) => {
+                        let run = if max_run.is_some() && last == mine { run + 1 } else { 1 };
+                        (losses, run)
+                    },
+                };
+                if run > states.runs {
+                    continue;
+                }
+                let losses = match (max_losses, mine_move.result(theirs)) {
+                    (Some(k), RoundResult::Loss) if losses == k => continue,
+                    (Some(_), RoundResult::Loss) => losses + 1,
+                    _ => losses,
+                };
+
+                let next = states.index(losses, mine, run);
+                let next_score = score + mine_move.score(theirs);
+                if next_scores[next].is_none_or(|best| next_score > best) {
+                    next_scores[next] = Some(next_score);
+                    next_previous[next] = start.map_or(usize::MAX, |(l, m, r)| states.index(l, m, r));
+                }
+            }
+        }
+        scores = next_scores;
+        previous.push(next_previous);
+    }
+
+    if opponent.is_empty() {
+        return Some(Plan { score: 0, moves: vec![] });
+    }
+
+    // Walk back from the best final state to recover the moves that reached it
+    let (mut state, score) = scores.iter()
+        .enumerate()
+        .filter_map(|(state, score)| score.map(|score| (state, score)))
+        .max_by_key(|&(state, score)| (score, std::cmp::Reverse(state)))?;
+    let mut moves = Vec::with_capacity(opponent.len());
+    for previous in previous.iter().rev() {
+        moves.push(RPS::CYCLE[state / states.runs % RPS::CYCLE.len()]);
+        state = previous[state];
+    }
+    moves.reverse();
+    Some(Plan { score, moves })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_guide, RoundResult, RPS};
+    use super::{best_plan, Constraints};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn opponent(guide: &str) -> Vec<RPS> {
+        parse_guide(guide).unwrap().into_iter().map(|(opp_move, _)| opp_move).collect()
+    }
+
+    #[test]
+    fn wins_every_round_without_constraints() {
+        let opponent = opponent(EXAMPLE);
+        let plan = best_plan(&opponent, &Constraints::default()).unwrap();
+        assert_eq!(plan.moves, vec![RPS::Paper, RPS::Scissors, RPS::Rock]);
+        assert_eq!(plan.score, 8 + 9 + 7);
+    }
+
+    #[test]
+    fn avoids_long_runs_and_losses() {
+        // Against rock every time the best is always paper, but that can only be played twice in a row
+        let opponent = opponent("A X\nA X\nA X\nA X\nA X\n");
+        let constraints = Constraints { max_losses: Some(0), max_run: Some(2) };
+        let plan = best_plan(&opponent, &constraints).unwrap();
+        assert_eq!(plan.moves, vec![RPS::Paper, RPS::Paper, RPS::Rock, RPS::Paper, RPS::Paper]);
+        assert_eq!(plan.score, 8 * 4 + 4);
+
+        // Scissors only scores a loss's 3, less than rock's draw
+        let constraints = Constraints { max_losses: Some(1), max_run: Some(1) };
+        let plan = best_plan(&opponent, &constraints).unwrap();
+        assert_eq!(plan.score, 8 * 3 + 4 * 2);
+        assert!(plan.results(&opponent).iter().all(|&r| r != RoundResult::Loss));
+
+        assert!(best_plan(&opponent, &Constraints { max_losses: None, max_run: Some(0) }).is_none());
+
+        // Limits longer than the guide don't limit anything
+        let constraints = Constraints { max_losses: Some(usize::MAX), max_run: Some(1_000_000_000) };
+        assert_eq!(best_plan(&opponent, &constraints).unwrap().score, 8 * 5);
+    }
+}
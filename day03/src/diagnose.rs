@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter};
+use crate::{priority, ItemSet};
+
+// Something wrong with a line, which parse_rucksacks would reject. Columns are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    OddLength(usize),
+    UnexpectedChar { column: usize, found: char },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::OddLength(len) => write!(f, "odd length {}", len),
+            Problem::UnexpectedChar { column, found } => write!(f, "unexpected {:?} at column {}", found, column),
+        }
+    }
+}
+
+// What's found in a single line: every item in both compartments, and anything malformed. Anything
+// that isn't an item is left out of the compartments, and the middle item of an odd length line is
+// counted in the second.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RucksackReport {
+    pub line: usize,
+    pub items: ItemSet,
+    pub misplaced: ItemSet,
+    pub problems: Vec<Problem>,
+}
+
+impl RucksackReport {
+    pub fn is_ambiguous(&self) -> bool {
+        self.misplaced.len() != 1
+    }
+}
+
+// Every item carried by all the rucksacks in a group, any of which could be its badge
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupReport {
+    pub first_line: usize,
+    pub last_line: usize,
+    pub candidates: ItemSet,
+    // The last group has fewer rucksacks when the lines don't divide into whole groups
+    pub incomplete: bool,
+}
+
+impl GroupReport {
+    pub fn is_ambiguous(&self) -> bool {
+        self.candidates.len() != 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub rucksacks: Vec<RucksackReport>,
+    pub groups: Vec<GroupReport>,
+}
+
+fn diagnose_line(line_number: usize, line: &str) -> RucksackReport {
+    let mut problems = vec![];
+    let len = line.chars().count();
+    if len % 2 == 1 {
+        problems.push(Problem::OddLength(len));
+    }
+
+    let mut compartments = [ItemSet::default(); 2];
+    for (i, c) in line.chars().enumerate() {
+        match priority(c) {
+            Some(priority) => compartments[(i >= len / 2) as usize].insert(priority),
+            None => problems.push(Problem::UnexpectedChar { column: i + 1, found: c }),
+        }
+    }
+    let [left, right] = compartments;
+    RucksackReport { line: line_number, items: left | right, misplaced: left & right, problems }
+}
+
+// Reports on every rucksack and every group of group_size (which mustn't be 0), without stopping at
+// anything malformed
+pub fn diagnose(input: &str, group_size: usize) -> Diagnosis {
+    let rucksacks = input.lines()
+        .enumerate()
+        .map(|(i, line)| diagnose_line(i + 1, line))
+        .collect::<Vec<_>>();
+    let groups = rucksacks.chunks(group_size)
+        .map(|group| GroupReport {
+            first_line: group[0].line,
+            last_line: group[group.len() - 1].line,
+            candidates: group.iter().fold(ItemSet::ALL, |common, r| common & r.items),
+            incomplete: group.len() < group_size,
+        })
+        .collect();
+    Diagnosis { rucksacks, groups }
+}
+
+fn describe_items(items: &ItemSet) -> String {
+    if items.is_empty() { "none".to_string() } else { items.to_string() }
+}
+
+impl Display for Diagnosis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for rucksack in &self.rucksacks {
+            write!(f, "line {}: shared {}", rucksack.line, describe_items(&rucksack.misplaced))?;
+            if rucksack.is_ambiguous() {
+                write!(f, " (ambiguous)")?;
+            }
+            for problem in &rucksack.problems {
+                write!(f, "; {}", problem)?;
+            }
+            writeln!(f)?;
+        }
+        for group in &self.groups {
+            write!(f, "lines {}-{}: badge {}", group.first_line, group.last_line, describe_items(&group.candidates))?;
+            if group.is_ambiguous() {
+                write!(f, " (ambiguous)")?;
+            }
+            if group.incomplete {
+                write!(f, "; incomplete group")?;
+            }
+            writeln!(f)?;
+        }
+
+        let flagged_rucksacks = self.rucksacks.iter().filter(|r| r.is_ambiguous() || !r.problems.is_empty()).count();
+        let flagged_groups = self.groups.iter().filter(|g| g.is_ambiguous() || g.incomplete).count();
+        write!(f, "{} rucksack(s) and {} group(s) flagged", flagged_rucksacks, flagged_groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diagnose, Problem};
+
+    #[test]
+    fn flags_malformed_and_ambiguous_rucksacks() {
+        let diagnosis = diagnose("abcABa\nxyzzyx\nab1AB\nbAxb\n", 3);
+
+        assert_eq!(diagnosis.rucksacks[0].misplaced.to_string(), "a");
+        assert_eq!(diagnosis.rucksacks[1].misplaced.to_string(), "xyz");
+        assert!(diagnosis.rucksacks[1].is_ambiguous());
+        assert_eq!(
+            diagnosis.rucksacks[2].problems,
+            vec![Problem::OddLength(5), Problem::UnexpectedChar { column: 3, found: '1' }]
+        );
+        assert_eq!(diagnosis.rucksacks[2].misplaced.to_string(), "");
+
+        assert_eq!(diagnosis.groups[0].candidates.to_string(), "");
+        assert_eq!((diagnosis.groups[1].first_line, diagnosis.groups[1].last_line), (4, 4));
+        assert!(diagnosis.groups[1].incomplete);
+
+        assert_eq!(
+            diagnosis.to_string(),
+            "line 1: shared a\nline 2: shared xyz (ambiguous)\nline 3: shared none (ambiguous); odd length 5; \
+            unexpected '1' at column 3\nline 4: shared b\nlines 1-3: badge none (ambiguous)\n\
+            lines 4-4: badge bxA (ambiguous); incomplete group\n2 rucksack(s) and 2 group(s) flagged"
+        );
+    }
+}
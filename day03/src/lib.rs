@@ -0,0 +1,205 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitOr};
+use aoc_common::ParseError;
+use aoc_common::parse::end_of;
+
+pub mod diagnose;
+
+// The number of elves in each group, which share a badge
+pub const ELF_GROUP_SIZE: usize = 3;
+
+pub fn priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 1 + 26),
+        _ => None,
+    }
+}
+
+pub fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        27..=52 => (b'A' + (priority - 27) as u8) as char,
+        _ => panic!("No item has priority {}", priority),
+    }
+}
+
+// A set of item types, as a bit for each, with the lowest bit for priority 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    // Parses items, a slice of text, rejecting anything that isn't an item type
+    pub fn parse(text: &str, items: &str) -> Result<ItemSet, ParseError> {
+        let mut set = ItemSet::default();
+        for (i, c) in items.char_indices() {
+            let priority = priority(c)
+                .ok_or_else(|| ParseError::at(text, &items[i..i + c.len_utf8()], "an item (a-z or A-Z)"))?;
+            set.insert(priority);
+        }
+        Ok(set)
+    }
+
+    pub fn insert(&mut self, priority: u32) {
+        self.0 |= 1 << (priority - 1);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    // The priorities of the items in the set, lowest first
+    pub fn priorities(&self) -> impl Iterator<Item = u32> {
+        let bits = self.0;
+        (0..52).filter(move |i| bits & (1 << i) != 0).map(|i| i + 1)
+    }
+
+    pub fn lowest_priority(&self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.0.trailing_zeros() + 1)
+    }
+}
+
+// The items, lowest priority first
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.priorities().try_for_each(|priority| write!(f, "{}", item(priority)))
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: ItemSet) -> ItemSet {
+        ItemSet(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: ItemSet) -> ItemSet {
+        ItemSet(self.0 | rhs.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: ItemSet,
+    pub right: ItemSet,
+}
+
+impl Rucksack {
+    // Parses line, a slice of text, as a rucksack with half its items in each compartment
+    pub fn parse(text: &str, line: &str) -> Result<Rucksack, ParseError> {
+        // Every item is a single byte, so once anything else is rejected the line can be split by byte
+        ItemSet::parse(text, line)?;
+        if line.len() % 2 == 1 {
+            return Err(ParseError::at(text, line, "an even number of items"));
+        }
+        let (lhs, rhs) = line.split_at(line.len() / 2);
+        Ok(Rucksack { left: ItemSet::parse(text, lhs)?, right: ItemSet::parse(text, rhs)? })
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.left | self.right
+    }
+
+    // The items in both compartments
+    pub fn misplaced(&self) -> ItemSet {
+        self.left & self.right
+    }
+}
+
+// The items carried by every elf in the group, one of which is their badge
+pub fn common_items(group: &[Rucksack]) -> ItemSet {
+    group.iter().fold(ItemSet::ALL, |common, rucksack| common & rucksack.items())
+}
+
+// Parses the rucksacks, checking that they form whole groups of group_size (which mustn't be 0), and
+// that each group shares an item for its badge
+pub fn parse_rucksacks(input: &str, group_size: usize) -> Result<Vec<Rucksack>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let rucksacks = lines.iter()
+        .map(|line| Rucksack::parse(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let leftover = rucksacks.len() % group_size;
+    if leftover != 0 {
+        let expected = format!(
+            "{} more rucksack(s) to complete the last group of {} ({} are listed)",
+            group_size - leftover, group_size, rucksacks.len()
+        );
+        return Err(ParseError::at(input, end_of(input), expected));
+    }
+    for (group, lines) in rucksacks.chunks(group_size).zip(lines.chunks(group_size)) {
+        if common_items(group).is_empty() {
+            let expected = format!("a rucksack sharing an item with the previous {}", group_size - 1);
+            return Err(ParseError::at(input, lines[group_size - 1], expected));
+        }
+    }
+    Ok(rucksacks)
+}
+
+// Rucksacks with no item in both compartments add nothing
+pub fn total_misplaced_priority(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks.iter()
+        .filter_map(|rucksack| rucksack.misplaced().lowest_priority())
+        .sum()
+}
+
+// The rucksacks must have been checked to form groups with badges by parse_rucksacks
+pub fn total_badge_priority(rucksacks: &[Rucksack], group_size: usize) -> u32 {
+    rucksacks.chunks(group_size)
+        .map(|group| common_items(group).lowest_priority().expect("Checked by parse_rucksacks"))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let rucksacks = parse_rucksacks(EXAMPLE, ELF_GROUP_SIZE).unwrap();
+        assert_eq!(total_misplaced_priority(&rucksacks), 157);
+    }
+
+    #[test]
+    fn example_part2() {
+        let rucksacks = parse_rucksacks(EXAMPLE, ELF_GROUP_SIZE).unwrap();
+        assert_eq!(total_badge_priority(&rucksacks, ELF_GROUP_SIZE), 70);
+    }
+
+    #[test]
+    fn groups_of_other_sizes() {
+        // Pairs of rucksacks share several items, and the lowest priority one is taken as the badge
+        let rucksacks = parse_rucksacks(EXAMPLE, 2).unwrap();
+        assert_eq!(common_items(&rucksacks[0..2]).priorities().collect::<Vec<_>>(), vec![6, 18, 19, 32, 39]);
+        assert_eq!(total_badge_priority(&rucksacks, 2), 6 + 17 + 33);
+
+        let error = parse_rucksacks(EXAMPLE, 6).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (6, "a rucksack sharing an item with the previous 5"));
+    }
+
+    #[test]
+    fn rejects_incomplete_groups() {
+        let error = parse_rucksacks(EXAMPLE, 4).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 1));
+        assert_eq!(error.expected, "2 more rucksack(s) to complete the last group of 4 (6 are listed)");
+    }
+
+    #[test]
+    fn rejects_non_item_characters() {
+        let error = parse_rucksacks("aéb\nabab\nabab\n", ELF_GROUP_SIZE).unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 2, "\"é\""));
+        assert_eq!(error.expected, "an item (a-z or A-Z)");
+    }
+}
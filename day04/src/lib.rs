@@ -0,0 +1,331 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{RangeBounds, RangeInclusive};
+use std::str::FromStr;
+use aoc_common::ParseError;
+use aoc_common::parse::{number, until};
+
+pub mod sweep;
+
+// A section ID. Sections are numbered consecutively, so each but the first and last has a section
+// just before and just after it.
+pub trait Section: FromStr + Ord + Copy {
+    fn next(self) -> Self;
+    fn prev(self) -> Self;
+    fn as_u64(self) -> u64;
+}
+
+macro_rules! impl_section {
+    ($($t:ty),*) => {
+        $(impl Section for $t {
+            fn next(self) -> Self {
+                self + 1
+            }
+
+            fn prev(self) -> Self {
+                self - 1
+            }
+
+            fn as_u64(self) -> u64 {
+                self as u64
+            }
+        })*
+    };
+}
+
+impl_section!(u8, u16, u32, u64, usize);
+
+pub trait ElfWorkAssignment<T> where T: FromStr, Self: RangeBounds<T> + Sized {
+    // Parses s, a slice of input, such as "2-4"
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError>;
+    fn overlaps(&self, other: &Self) -> bool;
+    fn contains_completely(&self, other: &Self) -> bool;
+    // The sections in both assignments
+    fn intersection(&self, other: &Self) -> Option<Self>;
+    // The sections in either assignment, if they overlap or are next to each other
+    fn union(&self, other: &Self) -> Option<Self>;
+    // The sections in this assignment but not the other, which may split it in two
+    fn difference(&self, other: &Self) -> Vec<Self>;
+    // The sections between the two assignments, if they're apart
+    fn gap(&self, other: &Self) -> Option<Self>;
+}
+
+impl <T> ElfWorkAssignment<T> for RangeInclusive<T> where T: Section {
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        let (left, right) = until(input, s, "-")?;
+        let start = number::<T>(input, left)?;
+        let end = number::<T>(input, right)?;
+        if end < start {
+            return Err(ParseError::at(input, right, "a section no lower than the start"));
+        }
+        Ok(start..=end)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        other.start() <= self.end() && other.end() >= self.start()
+    }
+
+    fn contains_completely(&self, other: &Self) -> bool {
+        self.contains(other.start()) && self.contains(other.end())
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = *self.start().max(other.start());
+        let end = *self.end().min(other.end());
+        (start <= end).then_some(start..=end)
+    }
+
+    fn union(&self, other: &Self) -> Option<Self> {
+        self.gap(other).is_none().then(|| *self.start().min(other.start())..=*self.end().max(other.end()))
+    }
+
+    fn difference(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+        let mut pieces = vec![];
+        if self.start() < other.start() {
+            pieces.push(*self.start()..=other.start().prev());
+        }
+        if self.end() > other.end() {
+            pieces.push(other.end().next()..=*self.end());
+        }
+        pieces
+    }
+
+    fn gap(&self, other: &Self) -> Option<Self> {
+        let (first, second) = if self.start() <= other.start() { (self, other) } else { (other, self) };
+        (*first.end() < *second.start() && first.end().next() < *second.start())
+            .then(|| first.end().next()..=second.start().prev())
+    }
+}
+
+// A set of sections, kept as the fewest assignments that cover it: in order, and neither
+// overlapping nor next to each other
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SectionSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for SectionSet<T> {
+    fn default() -> Self {
+        SectionSet { ranges: vec![] }
+    }
+}
+
+impl<T: Section> SectionSet<T> {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = RangeInclusive<T>>) -> SectionSet<T> {
+        let mut ranges = ranges.into_iter().filter(|r| !r.is_empty()).collect::<Vec<_>>();
+        ranges.sort_by_key(|r| *r.start());
+        let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut().and_then(|last| last.union(&range).map(|union| (last, union))) {
+                Some((last, union)) => *last = union,
+                None => merged.push(range),
+            }
+        }
+        SectionSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // The number of sections in the set
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end().as_u64() - r.start().as_u64() + 1).sum()
+    }
+
+    pub fn contains(&self, section: T) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < section);
+        self.ranges.get(i).is_some_and(|r| r.contains(&section))
+    }
+
+    pub fn union(&self, other: &SectionSet<T>) -> SectionSet<T> {
+        SectionSet::from_ranges(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    pub fn intersection(&self, other: &SectionSet<T>) -> SectionSet<T> {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            ranges.extend(a.intersection(b));
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        SectionSet { ranges }
+    }
+
+    pub fn difference(&self, other: &SectionSet<T>) -> SectionSet<T> {
+        match self.span() {
+            Some(span) => self.intersection(&other.complement(span)),
+            None => SectionSet::default(),
+        }
+    }
+
+    // The sections within bounds that aren't in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> SectionSet<T> {
+        let bounds = SectionSet::from_ranges([bounds]);
+        let Some(span) = bounds.span() else {
+            return bounds;
+        };
+        let mut ranges = vec![];
+        let mut next = Some(*span.start());
+        for range in self.ranges.iter().filter(|r| r.overlaps(&span)) {
+            if let Some(start) = next.filter(|&start| start < *range.start()) {
+                ranges.push(start..=range.start().prev());
+            }
+            next = (*range.end() < *span.end()).then(|| range.end().next());
+        }
+        ranges.extend(next.map(|start| start..=*span.end()));
+        SectionSet { ranges }
+    }
+
+    // The sections between the lowest and highest in the set that aren't in it
+    pub fn gaps(&self) -> SectionSet<T> {
+        let ranges = self.ranges.windows(2).filter_map(|pair| pair[0].gap(&pair[1])).collect();
+        SectionSet { ranges }
+    }
+
+    // From the lowest section in the set to the highest
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        Some(*self.ranges.first()?.start()..=*self.ranges.last()?.end())
+    }
+}
+
+// The runs of sections, like "2-4, 6, 8-9"
+impl<T: Section + Display> Display for SectionSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if range.start() == range.end() {
+                write!(f, "{}", range.start())?;
+            } else {
+                write!(f, "{}-{}", range.start(), range.end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub type RangePair = (RangeInclusive<u64>, RangeInclusive<u64>);
+
+pub fn parse_range_pairs(input: &str) -> Result<Vec<RangePair>, ParseError> {
+    input.lines().map(|line: &str| {
+        let (lhs, rhs) = until(input, line, ",")?;
+        Ok((RangeInclusive::<u64>::parse(input, lhs)?, RangeInclusive::<u64>::parse(input, rhs)?))
+    }).collect()
+}
+
+pub fn count_fully_contained(range_pairs: &[RangePair]) -> usize {
+    range_pairs.iter().filter(|(range1, range2)| {
+        range1.contains_completely(range2) || range2.contains_completely(range1)
+    }).count()
+}
+
+pub fn count_overlapping(range_pairs: &[RangePair]) -> usize {
+    range_pairs.iter().filter(|(range1, range2)| {
+        range1.overlaps(range2) || range2.overlaps(range1)
+    }).count()
+}
+
+fn assignments(range_pairs: &[RangePair]) -> impl Iterator<Item = &RangeInclusive<u64>> {
+    range_pairs.iter().flat_map(|(range1, range2)| [range1, range2])
+}
+
+// Every elf's assignment, in order, so the elves on line n are numbered 2n - 2 and 2n - 1
+pub fn all_assignments(range_pairs: &[RangePair]) -> Vec<RangeInclusive<u64>> {
+    assignments(range_pairs).cloned().collect()
+}
+
+// The sections no elf is assigned, between the lowest and highest that are
+pub fn unassigned_sections(range_pairs: &[RangePair]) -> SectionSet<u64> {
+    SectionSet::from_ranges(assignments(range_pairs).cloned()).gaps()
+}
+
+// The sections assigned to exactly one elf, across every pair
+pub fn sections_assigned_once(range_pairs: &[RangePair]) -> SectionSet<u64> {
+    let mut once = SectionSet::default();
+    let mut more = SectionSet::default();
+    for range in assignments(range_pairs) {
+        let range = SectionSet::from_ranges([range.clone()]);
+        let again = once.intersection(&range);
+        once = once.difference(&range).union(&range.difference(&once).difference(&more));
+        more = more.union(&again);
+    }
+    once
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(count_fully_contained(&parse_range_pairs(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(count_overlapping(&parse_range_pairs(EXAMPLE).unwrap()), 4);
+    }
+
+    #[test]
+    fn combines_assignments() {
+        assert_eq!((2..=6u64).intersection(&(4..=8)), Some(4..=6));
+        assert_eq!((2..=3u64).intersection(&(5..=8)), None);
+        assert_eq!((2..=3u64).union(&(4..=8)), Some(2..=8));
+        assert_eq!((2..=3u64).union(&(5..=8)), None);
+        assert_eq!((2..=8u64).difference(&(4..=5)), vec![2..=3, 6..=8]);
+        assert_eq!((0..=8u64).difference(&(0..=8)), vec![]);
+        assert_eq!((5..=8u64).gap(&(0..=1)), Some(2..=4));
+        assert_eq!((2..=3u64).gap(&(4..=8)), None);
+    }
+
+    #[test]
+    fn normalises_section_sets() {
+        let set = SectionSet::from_ranges([7..=9u64, 1..=2, 3..=4, 8..=8, 12..=12]);
+        assert_eq!(set.ranges(), &[1..=4, 7..=9, 12..=12]);
+        assert_eq!(set.len(), 8);
+        assert!(set.contains(8) && !set.contains(5));
+        assert_eq!(set.gaps().ranges(), &[5..=6, 10..=11]);
+        assert_eq!(set.complement(0..=10).ranges(), &[0..=0, 5..=6, 10..=10]);
+
+        let other = SectionSet::from_ranges([2..=8u64]);
+        assert_eq!(set.intersection(&other).ranges(), &[2..=4, 7..=8]);
+        assert_eq!(set.difference(&other).ranges(), &[1..=1, 9..=9, 12..=12]);
+        assert_eq!(set.union(&other).ranges(), &[1..=9, 12..=12]);
+        assert_eq!(set.to_string(), "1-4, 7-9, 12");
+    }
+
+    #[test]
+    fn finds_unassigned_and_singly_assigned_sections() {
+        let range_pairs = parse_range_pairs(EXAMPLE).unwrap();
+        assert!(unassigned_sections(&range_pairs).is_empty());
+        assert_eq!(sections_assigned_once(&range_pairs).ranges(), &[9..=9]);
+
+        let range_pairs = parse_range_pairs("1-3,2-5\n8-9,4-4\n").unwrap();
+        assert_eq!(unassigned_sections(&range_pairs).ranges(), &[6..=7]);
+        assert_eq!(sections_assigned_once(&range_pairs).ranges(), &[1..=1, 5..=5, 8..=9]);
+    }
+
+    #[test]
+    fn reports_bad_assignments() {
+        let error = parse_range_pairs("2-4,6-8\n2-3 4-5\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 8, "\",\""));
+        let error = parse_range_pairs("2-4,8-6\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 7, "\"6\""));
+        let error = RangeInclusive::<u8>::parse("1-300", "1-300").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (3, "a number"));
+    }
+}
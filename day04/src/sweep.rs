@@ -0,0 +1,148 @@
+use std::ops::RangeInclusive;
+use crate::SectionSet;
+
+// What's found by sweeping across every assignment at once. Elves are numbered from 0 in the order
+// their assignments are given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sweep {
+    // The most elves assigned any one section
+    pub peak: usize,
+    // The sections assigned to that many elves
+    pub peak_sections: SectionSet<u64>,
+    // For each elf, how many other elves' assignments overlap theirs
+    pub overlap_counts: Vec<usize>,
+    // The number of elves assigned each run of sections, in order, from the lowest section assigned
+    // to the highest
+    pub coverage: Vec<(RangeInclusive<u64>, usize)>,
+}
+
+impl Sweep {
+    // The sections assigned to exactly count elves, within the assigned span
+    pub fn sections_with(&self, count: usize) -> SectionSet<u64> {
+        SectionSet::from_ranges(
+            self.coverage.iter().filter(|(_, c)| *c == count).map(|(range, _)| range.clone())
+        )
+    }
+}
+
+// Sweeps through the sections in order, starting and ending assignments as they're met, which takes
+// O(n log n) time to sort the starts and ends. Elves are only counted as they pass each other, not
+// listed, as there can be O(n^2) pairs of them.
+pub fn sweep(assignments: &[RangeInclusive<u64>]) -> Sweep {
+    // At each section, assignments starting there are met before those ending there, as both
+    // include it
+    let mut events = assignments.iter()
+        .filter(|range| !range.is_empty())
+        .flat_map(|range| [(*range.start(), false), (*range.end(), true)])
+        .collect::<Vec<_>>();
+    events.sort_unstable();
+
+    let mut active = 0;
+    let mut coverage: Vec<(RangeInclusive<u64>, usize)> = vec![];
+    let mut i = 0;
+    while i < events.len() {
+        let section = events[i].0;
+        while events.get(i).is_some_and(|&event| event == (section, false)) {
+            active += 1;
+            i += 1;
+        }
+        coverage.push((section..=section, active));
+
+        while events.get(i).is_some_and(|&event| event == (section, true)) {
+            active -= 1;
+            i += 1;
+        }
+        // Sections after this one and before the next event are assigned to whoever's still active
+        if let Some(&(next, _)) = events.get(i) {
+            if section + 1 < next {
+                coverage.push((section + 1..=next - 1, active));
+            }
+        }
+    }
+
+    let peak = coverage.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let overlap_counts = count_overlaps(assignments);
+    let mut sweep = Sweep { peak, peak_sections: SectionSet::default(), overlap_counts, coverage };
+    if peak > 0 {
+        sweep.peak_sections = sweep.sections_with(peak);
+    }
+    sweep
+}
+
+// Every assignment that starts no later than an elf's ends overlaps it, unless it ends before the
+// elf's starts, so both can be counted by binary searching the sorted starts and ends
+fn count_overlaps(assignments: &[RangeInclusive<u64>]) -> Vec<usize> {
+    let assigned = || assignments.iter().filter(|range| !range.is_empty());
+    let mut starts = assigned().map(|range| *range.start()).collect::<Vec<_>>();
+    let mut ends = assigned().map(|range| *range.end()).collect::<Vec<_>>();
+    starts.sort_unstable();
+    ends.sort_unstable();
+
+    assignments.iter()
+        .map(|range| {
+            if range.is_empty() {
+                return 0;
+            }
+            let started = starts.partition_point(|&start| start <= *range.end());
+            let ended = ends.partition_point(|&end| end < *range.start());
+            // Not counting the elf's own assignment
+            started - ended - 1
+        })
+        .collect()
+}
+
+// The other elves whose assignments overlap elf's, in order
+pub fn overlapping(assignments: &[RangeInclusive<u64>], elf: usize) -> Vec<usize> {
+    let range = &assignments[elf];
+    if range.is_empty() {
+        return vec![];
+    }
+    assignments.iter()
+        .enumerate()
+        .filter(|&(other, other_range)| {
+            other != elf && !other_range.is_empty()
+                && other_range.start() <= range.end() && range.start() <= other_range.end()
+        })
+        .map(|(other, _)| other)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{all_assignments, parse_range_pairs};
+    use super::{overlapping, sweep};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn finds_peak_and_overlaps() {
+        let assignments = all_assignments(&parse_range_pairs(EXAMPLE).unwrap());
+        let sweep = sweep(&assignments);
+
+        // Section 6 is in 6-8, 5-7, 2-8, 3-7, 6-6, 4-6, 2-6 and 4-8
+        assert_eq!(sweep.peak, 8);
+        assert_eq!(sweep.peak_sections.ranges(), &[6..=6]);
+        assert_eq!(sweep.overlap_counts, vec![7, 8, 4, 7, 9, 5, 11, 11, 7, 9, 10, 10]);
+        for (elf, &count) in sweep.overlap_counts.iter().enumerate() {
+            assert_eq!(overlapping(&assignments, elf).len(), count, "Elf {}", elf);
+        }
+        assert_eq!(overlapping(&assignments, 0), vec![2, 3, 6, 7, 9, 10, 11]);
+        assert_eq!(overlapping(&assignments, 8), vec![1, 4, 6, 7, 9, 10, 11]);
+        assert_eq!(sweep.sections_with(1).ranges(), &[9..=9]);
+    }
+
+    #[test]
+    fn peaks_across_runs_of_sections() {
+        let assignments = [1..=10, 3..=4, 4..=7, 9..=12, 20..=25u64];
+        let sweep = sweep(&assignments);
+        assert_eq!(sweep.peak, 3);
+        assert_eq!(sweep.peak_sections.ranges(), &[4..=4]);
+        assert_eq!(sweep.overlap_counts, vec![3, 2, 2, 1, 0]);
+        assert_eq!(overlapping(&assignments, 1), vec![0, 2]);
+        assert_eq!(sweep.sections_with(0).ranges(), &[13..=19]);
+        assert_eq!(sweep.sections_with(1).ranges(), &[1..=2, 8..=8, 11..=12, 20..=25]);
+
+        let sweep = super::sweep(&[1..=3, 4..=6, 2..=5u64]);
+        assert_eq!((sweep.peak, sweep.peak_sections.ranges()), (2, &[2..=5][..]));
+    }
+}
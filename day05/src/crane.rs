@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
+use crate::{ProcStep, Stacks};
+
+// Why a step of the procedure can't be carried out. Stacks are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IllegalMove {
+    NoSuchStack(usize),
+    NotEnoughCrates { stack: usize, needed: usize, available: usize },
+    NotAdjacent { from: usize, to: usize },
+}
+
+impl Display for IllegalMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IllegalMove::NoSuchStack(stack) => write!(f, "there's no stack {}", stack),
+            IllegalMove::NotEnoughCrates { stack, needed, available } =>
+                write!(f, "can't move {} crate(s) from stack {}, which has {}", needed, stack, available),
+            IllegalMove::NotAdjacent { from, to } =>
+                write!(f, "can't move from stack {} to stack {}, which isn't next to it", from, to),
+        }
+    }
+}
+
+// An illegal move, and which step of the procedure (numbered from 1) asked for it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveError {
+    pub step: usize,
+    pub problem: IllegalMove,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "step {}: {}", self.step, self.problem)
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+pub trait Crane {
+    // Carries out a single step. An illegal step leaves the stacks as they were.
+    fn apply_step(&self, stacks: &mut Stacks, step: &ProcStep) -> Result<(), IllegalMove>;
+
+    // Carries out each step in turn, stopping at the first illegal one, with the stacks left as they
+    // were after the step before it
+    fn apply(&self, stacks: &mut Stacks, proc_steps: &[ProcStep]) -> Result<(), MoveError> {
+        for (i, step) in proc_steps.iter().enumerate() {
+            self.apply_step(stacks, step).map_err(|problem| MoveError { step: i + 1, problem })?;
+        }
+        Ok(())
+    }
+}
+
+// So that the model can be chosen at runtime
+impl<C: Crane + ?Sized> Crane for Box<C> {
+    fn apply_step(&self, stacks: &mut Stacks, step: &ProcStep) -> Result<(), IllegalMove> {
+        (**self).apply_step(stacks, step)
+    }
+}
+
+// Checks that both stacks exist and there are enough crates to move
+fn check(stacks: &Stacks, step: &ProcStep) -> Result<(), IllegalMove> {
+    for stack in [step.from, step.to] {
+        if !(1..=stacks.len()).contains(&stack) {
+            return Err(IllegalMove::NoSuchStack(stack));
+        }
+    }
+    let available = stacks[step.from - 1].len();
+    if available < step.num {
+        return Err(IllegalMove::NotEnoughCrates { stack: step.from, needed: step.num, available });
+    }
+    Ok(())
+}
+
+// Moves the top num crates from one stack to another, keeping them in order
+fn lift(stacks: &mut Stacks, from: usize, to: usize, num: usize) {
+    let from_stack = &mut stacks[from - 1];
+    let crates = from_stack.split_off(from_stack.len() - num);
+    stacks[to - 1].extend(crates);
+}
+
+// Moves crates one at a time, so reversing their order
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn apply_step(&self, stacks: &mut Stacks, step: &ProcStep) -> Result<(), IllegalMove> {
+        check(stacks, step)?;
+        for _ in 0..step.num {
+            lift(stacks, step.from, step.to, 1);
+        }
+        Ok(())
+    }
+}
+
+// Moves all the crates at once, keeping their order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn apply_step(&self, stacks: &mut Stacks, step: &ProcStep) -> Result<(), IllegalMove> {
+        check(stacks, step)?;
+        lift(stacks, step.from, step.to, step.num);
+        Ok(())
+    }
+}
+
+// Moves at most capacity crates at once, splitting bigger moves into as many full lifts as it takes,
+// and then whatever's left
+pub struct LimitedCrane {
+    pub capacity: NonZeroUsize,
+}
+
+impl Crane for LimitedCrane {
+    fn apply_step(&self, stacks: &mut Stacks, step: &ProcStep) -> Result<(), IllegalMove> {
+        check(stacks, step)?;
+        let mut remaining = step.num;
+        while remaining > 0 {
+            let num = remaining.min(self.capacity.get());
+            lift(stacks, step.from, step.to, num);
+            remaining -= num;
+        }
+        Ok(())
+    }
+}
+
+// Another crane, whose track only reaches from each stack to those on either side of it
+pub struct AdjacentCrane<C>(pub C);
+
+impl<C: Crane> Crane for AdjacentCrane<C> {
+    fn apply_step(&self, stacks: &mut Stacks, step: &ProcStep) -> Result<(), IllegalMove> {
+        if step.from.abs_diff(step.to) != 1 {
+            return Err(IllegalMove::NotAdjacent { from: step.from, to: step.to });
+        }
+        self.0.apply_step(stacks, step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, tops_of_stacks, ProcStep};
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn limited_crane_splits_big_moves() {
+        let mut stacks = vec![vec!['A', 'B', 'C', 'D', 'E'], vec![]];
+        let step = ProcStep { num: 5, from: 1, to: 2 };
+        LimitedCrane { capacity: NonZeroUsize::new(2).unwrap() }.apply(&mut stacks, &[step]).unwrap();
+        assert_eq!(stacks, vec![vec![], vec!['D', 'E', 'B', 'C', 'A']]);
+
+        // Without a limit that stops it, it's the same as the other two models
+        let (initial_stacks, proc_steps) = parse_input(EXAMPLE).unwrap();
+        for (capacity, expected) in [(1, "CMZ"), (3, "MCD")] {
+            let mut stacks = initial_stacks.clone();
+            LimitedCrane { capacity: NonZeroUsize::new(capacity).unwrap() }.apply(&mut stacks, &proc_steps).unwrap();
+            assert_eq!(tops_of_stacks(&stacks), expected);
+        }
+    }
+
+    #[test]
+    fn reports_illegal_moves() {
+        let (initial_stacks, proc_steps) = parse_input(EXAMPLE).unwrap();
+
+        // The second step moves three crates from stack 1, which only has them after the first
+        let mut stacks = initial_stacks.clone();
+        let error = CrateMover9001.apply(&mut stacks, &proc_steps[1..]).unwrap_err();
+        let problem = IllegalMove::NotEnoughCrates { stack: 1, needed: 3, available: 2 };
+        assert_eq!(error, MoveError { step: 1, problem });
+        assert_eq!(stacks, initial_stacks);
+
+        let mut stacks = initial_stacks.clone();
+        let crane: Box<dyn Crane> = Box::new(AdjacentCrane(CrateMover9000));
+        let error = crane.apply(&mut stacks, &proc_steps).unwrap_err();
+        assert_eq!(error.to_string(), "step 2: can't move from stack 1 to stack 3, which isn't next to it");
+        assert_eq!(stacks, vec![vec!['Z', 'N', 'D'], vec!['M', 'C'], vec!['P']]);
+
+        let error = CrateMover9000.apply(&mut stacks, &[ProcStep { num: 1, from: 4, to: 1 }]).unwrap_err();
+        assert_eq!(error.problem, IllegalMove::NoSuchStack(4));
+    }
+}
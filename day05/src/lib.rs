@@ -0,0 +1,199 @@
+use std::fmt::{Display, Formatter};
+use aoc_common::ParseError;
+use aoc_common::parse::{end_of, literal, number, until};
+
+pub mod crane;
+pub mod replay;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProcStep {
+    pub num: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl ProcStep {
+    // Parses a line of the form "move 1 from 2 to 3"
+    pub fn parse(s: &str) -> Result<ProcStep, ParseError> {
+        let rest = literal(s, s, "move ")?;
+        let (num, rest) = until(s, rest, " ")?;
+        let rest = literal(s, rest, "from ")?;
+        let (from, rest) = until(s, rest, " ")?;
+        let to = literal(s, rest, "to ")?;
+        Ok(ProcStep { num: number(s, num)?, from: number(s, from)?, to: number(s, to)? })
+    }
+}
+
+impl Display for ProcStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} from {} to {}", self.num, self.from, self.to)
+    }
+}
+
+// Each stack's crates, from the bottom up
+pub type Stacks = Vec<Vec<char>>;
+
+// Parses a row of the drawing, with a cell for each stack: either "[X]" for a crate or blank. Rows
+// may stop short once the remaining cells are blank.
+fn parse_row(input: &str, line: &str, num_stacks: usize) -> Result<Vec<Option<char>>, ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "a crate or a space"));
+    }
+    let mut row = vec![None; num_stacks];
+    for (i, start) in (0..line.len()).step_by(4).enumerate() {
+        let cell = &line[start..(start + 3).min(line.len())];
+        if i >= num_stacks {
+            if cell.trim().is_empty() {
+                continue;
+            }
+            return Err(ParseError::at(input, cell, format!("nothing past stack {}", num_stacks)));
+        }
+        if start > 0 && &line[start - 1..start] != " " {
+            return Err(ParseError::at(input, &line[start - 1..start], "a space between stacks"));
+        }
+        row[i] = match cell.as_bytes() {
+            [b'[', c, b']'] if c.is_ascii_alphanumeric() => Some(*c as char),
+            _ if cell.trim().is_empty() => None,
+            _ => return Err(ParseError::at(input, cell, "a crate, like \"[A]\", or a blank space")),
+        };
+    }
+    Ok(row)
+}
+
+// Parses the drawing of the starting stacks, lines of input: rows of crates, with the stacks
+// numbered from 1 beneath them
+pub fn parse_stacks(input: &str, drawing: &[&str]) -> Result<Stacks, ParseError> {
+    let Some((footer, rows)) = drawing.split_last() else {
+        return Err(ParseError::at(input, &input[..0], "a drawing of the stacks"));
+    };
+    let mut num_stacks = 0;
+    for label in footer.split_whitespace() {
+        if label != (num_stacks + 1).to_string() {
+            return Err(ParseError::at(input, label, format!("stack number {}", num_stacks + 1)));
+        }
+        num_stacks += 1;
+    }
+    if num_stacks == 0 {
+        return Err(ParseError::at(input, footer, "the stack numbers"));
+    }
+
+    // Build the stacks from the floor up, so a crate with nothing beneath it can be spotted
+    let mut stacks: Stacks = vec![vec![]; num_stacks];
+    for (height, line) in rows.iter().rev().enumerate() {
+        for (i, cell) in parse_row(input, line, num_stacks)?.into_iter().enumerate() {
+            if let Some(c) = cell {
+                if stacks[i].len() < height {
+                    let expected = format!("a crate beneath this one in stack {}", i + 1);
+                    return Err(ParseError::at(input, &line[i * 4..i * 4 + 3], expected));
+                }
+                stacks[i].push(c);
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+// Draws the stacks the way the input does
+pub fn draw_stacks(stacks: &Stacks) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for level in (0..height).rev() {
+        let row = stacks.iter()
+            .map(|stack| stack.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+            .collect::<Vec<_>>();
+        out.push_str(row.join(" ").trim_end());
+        out.push('\n');
+    }
+    let labels = (1..=stacks.len()).map(|i| format!("{:^3}", i)).collect::<Vec<_>>();
+    out.push_str(labels.join(" ").trim_end());
+    out.push('\n');
+    out
+}
+
+// Parses the drawing of the starting stacks, then after a blank line, the procedure
+pub fn parse_input(input: &str) -> Result<(Stacks, Vec<ProcStep>), ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let Some(blank) = lines.iter().position(|line| line.is_empty()) else {
+        return Err(ParseError::at(input, end_of(input), "a blank line after the drawing of the stacks"));
+    };
+    let stacks = parse_stacks(input, &lines[..blank])?;
+
+    let mut proc_steps = vec![];
+    for line in &lines[blank + 1..] {
+        let step = ProcStep::parse(line).map_err(|e| e.within(input, line))?;
+        // Having parsed, the line must be "move N from A to B"
+        let tokens = line.split(' ').collect::<Vec<_>>();
+        for (stack, token) in [(step.from, tokens[3]), (step.to, tokens[5])] {
+            if !(1..=stacks.len()).contains(&stack) {
+                let expected = format!("a stack number from 1 to {}", stacks.len());
+                return Err(ParseError::at(input, token, expected));
+            }
+        }
+        proc_steps.push(step);
+    }
+    Ok((stacks, proc_steps))
+}
+
+// Empty stacks have no crate on top, so add nothing
+pub fn tops_of_stacks(stacks: &Stacks) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::crane::{Crane, CrateMover9000, CrateMover9001};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let (mut stacks, proc_steps) = parse_input(EXAMPLE).unwrap();
+        CrateMover9000.apply(&mut stacks, &proc_steps).unwrap();
+        assert_eq!(tops_of_stacks(&stacks), "CMZ");
+    }
+
+    #[test]
+    fn example_part2() {
+        let (mut stacks, proc_steps) = parse_input(EXAMPLE).unwrap();
+        CrateMover9001.apply(&mut stacks, &proc_steps).unwrap();
+        assert_eq!(tops_of_stacks(&stacks), "MCD");
+    }
+
+    #[test]
+    fn parses_ragged_drawings() {
+        let (stacks, _) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(stacks, vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+        assert_eq!(draw_stacks(&stacks), "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n");
+
+        // Rows needn't be padded out to the last stack, and the numbers may go past 9
+        let input = "[A]\n[B]             [C]\n 1   2   3   4   5   6   7   8   9   10  11\n\nmove 1 from 1 to 11\n";
+        let (stacks, proc_steps) = parse_input(input).unwrap();
+        assert_eq!(stacks.len(), 11);
+        assert_eq!((&stacks[0], &stacks[4]), (&vec!['B', 'A'], &vec!['C']));
+        assert_eq!(proc_steps[0].to, 11);
+        assert_eq!(parse_input(&format!("{}\n", draw_stacks(&stacks))).unwrap().0, stacks);
+    }
+
+    #[test]
+    fn rejects_bad_drawings() {
+        let error = parse_input("[A] [B]\n 1   3\n\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 6, "stack number 2"));
+
+        let error = parse_input("    [A]\n[B]\n 1   2\n\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.expected, "a crate beneath this one in stack 2");
+
+        let error = parse_input("[A] [B] [C]\n 1   2\n\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 9, "nothing past stack 2"));
+
+        let error = parse_input("[A] (B)\n 1   2\n\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+
+        let error = parse_input("[A] [B]\n 1   2\n\nmove 1 from 3 to 1\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (4, 13, "a stack number from 1 to 2"));
+
+        let error = parse_input("[A] [B]\n 1   2\n").unwrap_err();
+        assert_eq!(error.expected, "a blank line after the drawing of the stacks");
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt::{Display, Formatter};
+use crate::crane::{Crane, MoveError};
+use crate::{draw_stacks, ProcStep, Stacks};
+
+// Steps a crane through a procedure one step at a time, in either direction
+pub struct Replay<'a, C> {
+    crane: C,
+    proc_steps: &'a [ProcStep],
+    stacks: Stacks,
+    // For each step taken, the crates it took off the stack it moved them from, bottom first. Any
+    // crane puts the crates it takes on top of the stack it moves them to, so this is all that's
+    // needed to undo the step.
+    taken: Vec<Vec<char>>,
+}
+
+impl<'a, C: Crane> Replay<'a, C> {
+    pub fn new(crane: C, stacks: Stacks, proc_steps: &'a [ProcStep]) -> Replay<'a, C> {
+        Replay { crane, proc_steps, stacks, taken: vec![] }
+    }
+
+    // The number of steps taken so far
+    pub fn position(&self) -> usize {
+        self.taken.len()
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    // The stacks as they are now, drawn the way the input does
+    pub fn render(&self) -> String {
+        draw_stacks(&self.stacks)
+    }
+
+    // Takes the next step, returning false if there are none left
+    pub fn step(&mut self) -> Result<bool, MoveError> {
+        let Some(step) = self.proc_steps.get(self.position()) else {
+            return Ok(false);
+        };
+        let from_stack = step.from.checked_sub(1)
+            .and_then(|i| self.stacks.get(i))
+            .filter(|stack| stack.len() >= step.num);
+        let taken = from_stack.map_or(vec![], |stack| stack[stack.len() - step.num..].to_vec());
+        self.crane.apply_step(&mut self.stacks, step)
+            .map_err(|problem| MoveError { step: self.position() + 1, problem })?;
+        self.taken.push(taken);
+        Ok(true)
+    }
+
+    // Undoes the last step taken, returning false if there are none
+    pub fn undo(&mut self) -> bool {
+        let Some(taken) = self.taken.pop() else {
+            return false;
+        };
+        let step = &self.proc_steps[self.position()];
+        let to_stack = &mut self.stacks[step.to - 1];
+        to_stack.truncate(to_stack.len() - step.num);
+        self.stacks[step.from - 1].extend(taken);
+        true
+    }
+
+    // Steps forwards or backwards until position steps have been taken, or all of them if there
+    // aren't that many
+    pub fn seek(&mut self, position: usize) -> Result<(), MoveError> {
+        while self.position() > position {
+            self.undo();
+        }
+        while self.position() < position && self.step()? {}
+        Ok(())
+    }
+
+    // Goes back to the start, then steps through the whole procedure, keeping the stacks after
+    // every step, until the end or the first illegal step
+    pub fn history(&mut self) -> History {
+        self.seek(0).expect("Going backwards is always legal");
+        let mut snapshots = vec![self.stacks.clone()];
+        let error = loop {
+            match self.step() {
+                Ok(true) => snapshots.push(self.stacks.clone()),
+                Ok(false) => break None,
+                Err(e) => break Some(e),
+            }
+        };
+        History { proc_steps: self.proc_steps.to_vec(), snapshots, error }
+    }
+}
+
+// The stacks at the start and after each step taken, and the illegal step that stopped it, if one did
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct History {
+    pub proc_steps: Vec<ProcStep>,
+    pub snapshots: Vec<Stacks>,
+    pub error: Option<MoveError>,
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, stacks) in self.snapshots.iter().enumerate() {
+            if position == 0 {
+                writeln!(f, "Start:")?;
+            } else {
+                writeln!(f)?;
+                writeln!(f, "After step {}, {}:", position, self.proc_steps[position - 1])?;
+            }
+            write!(f, "{}", draw_stacks(stacks))?;
+        }
+        if let Some(e) = &self.error {
+            writeln!(f)?;
+            writeln!(f, "Stopped at {}", e)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use crate::crane::{CrateMover9000, CrateMover9001, IllegalMove, LimitedCrane};
+    use crate::parse_input;
+    use super::Replay;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn steps_backwards_and_forwards() {
+        let (initial_stacks, proc_steps) = parse_input(EXAMPLE).unwrap();
+        let crane = LimitedCrane { capacity: NonZeroUsize::new(2).unwrap() };
+        let mut replay = Replay::new(crane, initial_stacks.clone(), &proc_steps);
+
+        // The second step lifts N and D together, then Z
+        replay.seek(2).unwrap();
+        assert_eq!(replay.render(), "        [Z]\n        [D]\n    [C] [N]\n    [M] [P]\n 1   2   3\n");
+        let after_two = replay.stacks().clone();
+        replay.seek(10).unwrap();
+        assert_eq!((replay.position(), replay.stacks()), (4, &vec![vec!['M'], vec!['C'], vec!['P', 'N', 'D', 'Z']]));
+
+        assert!(replay.undo() && replay.undo());
+        assert_eq!(replay.stacks(), &after_two);
+        replay.seek(0).unwrap();
+        assert_eq!(replay.stacks(), &initial_stacks);
+        assert!(!replay.undo());
+    }
+
+    #[test]
+    fn dumps_history() {
+        let (initial_stacks, proc_steps) = parse_input(EXAMPLE).unwrap();
+        let history = Replay::new(CrateMover9001, initial_stacks.clone(), &proc_steps).history();
+        assert_eq!(history.snapshots.len(), 5);
+        assert!(history.to_string().starts_with(
+            "Start:\n    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nAfter step 1, move 1 from 2 to 1:\n[D]\n[N] [C]\n"
+        ));
+
+        // Skipping the first step leaves too few crates on stack 1 for the next
+        let history = Replay::new(CrateMover9000, initial_stacks, &proc_steps[1..]).history();
+        assert_eq!(history.snapshots.len(), 1);
+        let error = history.error.as_ref().unwrap();
+        let problem = IllegalMove::NotEnoughCrates { stack: 1, needed: 3, available: 2 };
+        assert_eq!((error.step, &error.problem), (1, &problem));
+        assert!(history.to_string().ends_with("\nStopped at step 1: can't move 3 crate(s) from stack 1, which has 2\n"));
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::HashSet;
+use std::io::{self, BufReader, Bytes, Read};
+use aoc_common::ParseError;
+
+pub const PACKET_MARKER_LEN: usize = 4;
+pub const MESSAGE_MARKER_LEN: usize = 14;
+
+// Looks for markers, len characters that are all different, one character at a time. Each marker is
+// looked for after the end of the one before, so they never overlap.
+//
+// This keeps where each character was last seen. The window starts just after the last repeat of
+// anything in it, so it's a marker once it's grown to len, without ever looking back through it.
+// That makes it O(n) whatever len is.
+struct Scanner {
+    len: usize,
+    last_seen: [Option<usize>; 256],
+    start: usize,
+    read: usize,
+}
+
+impl Scanner {
+    fn new(len: usize) -> Scanner {
+        Scanner { len, last_seen: [None; 256], start: 0, read: 0 }
+    }
+
+    // Reads the next character, returning whether it ends a marker
+    fn push(&mut self, byte: u8) -> bool {
+        let i = self.read;
+        self.read += 1;
+        if let Some(j) = self.last_seen[byte as usize].filter(|&j| j >= self.start) {
+            self.start = j + 1;
+        }
+        self.last_seen[byte as usize] = Some(i);
+        if self.read - self.start == self.len {
+            self.start = self.read;
+            return true;
+        }
+        false
+    }
+}
+
+// The bytes of the datastream in a reader, which is its first line, so a line break ends it. Each
+// must be ASCII, so that a byte is a character.
+struct DatastreamBytes<R> {
+    bytes: Bytes<BufReader<R>>,
+    read: usize,
+    done: bool,
+}
+
+impl<R: Read> Iterator for DatastreamBytes<R> {
+    type Item = io::Result<u8>;
+
+    fn next(&mut self) -> Option<io::Result<u8>> {
+        if self.done {
+            return None;
+        }
+        let byte = match self.bytes.next() {
+            Some(Ok(b'\n' | b'\r')) | None => None,
+            Some(Ok(byte)) if !byte.is_ascii() => Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected an ASCII character at position {}, found byte {:#04x}", self.read + 1, byte),
+            ))),
+            result => result,
+        };
+        self.read += 1;
+        self.done = !matches!(byte, Some(Ok(_)));
+        byte
+    }
+}
+
+fn datastream_bytes<R: Read>(reader: R) -> DatastreamBytes<R> {
+    DatastreamBytes { bytes: BufReader::new(reader).bytes(), read: 0, done: false }
+}
+
+// The number of characters read by the end of each marker in a reader
+pub struct Markers<R> {
+    bytes: DatastreamBytes<R>,
+    scanner: Scanner,
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = io::Result<usize>;
+
+    fn next(&mut self) -> Option<io::Result<usize>> {
+        for byte in self.bytes.by_ref() {
+            match byte {
+                Ok(byte) => if self.scanner.push(byte) {
+                    return Some(Ok(self.scanner.read));
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        None
+    }
+}
+
+pub fn markers<R: Read>(reader: R, len: usize) -> Markers<R> {
+    Markers { bytes: datastream_bytes(reader), scanner: Scanner::new(len) }
+}
+
+// The number of characters read by the end of the first marker, or None if there isn't one
+pub fn find_position_of_marker(reader: impl Read, len: usize) -> io::Result<Option<usize>> {
+    markers(reader, len).next().transpose()
+}
+
+// The first marker of each length, found in a single pass over reader, so that it needn't be read
+// again (or held in memory) to look for more than one
+pub fn find_positions_of_markers<const N: usize>(reader: impl Read, lens: [usize; N]) -> io::Result<[Option<usize>; N]> {
+    let mut scanners = lens.map(Scanner::new);
+    let mut positions = [None; N];
+    for byte in datastream_bytes(reader) {
+        let byte = byte?;
+        for (scanner, position) in scanners.iter_mut().zip(&mut positions) {
+            if position.is_none() && scanner.push(byte) {
+                *position = Some(scanner.read);
+            }
+        }
+        if positions.iter().all(Option::is_some) {
+            break;
+        }
+    }
+    Ok(positions)
+}
+
+pub fn find_position_of_marker_by_windows(input: &str, len: usize) -> Option<usize> {
+    input.as_bytes()
+        .windows(len)
+        .position(|buffer| buffer.iter().collect::<HashSet<_>>().len() == buffer.len())
+        .map(|position| position + len)
+}
+
+// A marker, and everything after it up to the next marker or the end of the datastream. Positions
+// are the number of characters before each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segment<'a> {
+    pub marker_position: usize,
+    pub marker: &'a str,
+    pub content_position: usize,
+    pub content: &'a str,
+}
+
+// A datastream held in memory, which can be split into packets and messages. Markers may be any
+// length but 0.
+#[derive(Clone, Debug)]
+pub struct Datastream<'a> {
+    stream: &'a str,
+    pub packet_marker_len: usize,
+    pub message_marker_len: usize,
+}
+
+impl<'a> Datastream<'a> {
+    // Parses the first line of input, which must be ASCII
+    pub fn parse(input: &'a str) -> Result<Datastream<'a>, ParseError> {
+        let stream = input.lines().next().unwrap_or("");
+        if let Some((i, c)) = stream.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::at(input, &stream[i..i + c.len_utf8()], "an ASCII character"));
+        }
+        Ok(Datastream { stream, packet_marker_len: PACKET_MARKER_LEN, message_marker_len: MESSAGE_MARKER_LEN })
+    }
+
+    pub fn with_marker_lens(self, packet_marker_len: usize, message_marker_len: usize) -> Datastream<'a> {
+        Datastream { packet_marker_len, message_marker_len, ..self }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.stream
+    }
+
+    // The number of characters before the end of each marker of len characters
+    pub fn markers(&self, len: usize) -> impl Iterator<Item = usize> + 'a {
+        let mut scanner = Scanner::new(len);
+        self.stream.bytes().filter_map(move |byte| scanner.push(byte).then_some(scanner.read))
+    }
+
+    pub fn packet_markers(&self) -> impl Iterator<Item = usize> + 'a {
+        self.markers(self.packet_marker_len)
+    }
+
+    pub fn message_markers(&self) -> impl Iterator<Item = usize> + 'a {
+        self.markers(self.message_marker_len)
+    }
+
+    // Splits the datastream at each marker of len characters. Anything before the first marker
+    // isn't in any segment.
+    pub fn segments(&self, len: usize) -> Vec<Segment<'a>> {
+        let ends = self.markers(len).collect::<Vec<_>>();
+        ends.iter()
+            .enumerate()
+            .map(|(i, &end)| {
+                let next = ends.get(i + 1).map_or(self.stream.len(), |next_end| next_end - len);
+                Segment {
+                    marker_position: end - len,
+                    marker: &self.stream[end - len..end],
+                    content_position: end,
+                    content: &self.stream[end..next],
+                }
+            })
+            .collect()
+    }
+
+    pub fn packets(&self) -> Vec<Segment<'a>> {
+        self.segments(self.packet_marker_len)
+    }
+
+    pub fn messages(&self) -> Vec<Segment<'a>> {
+        self.segments(self.message_marker_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(find_position_of_marker(EXAMPLE.as_bytes(), 4).unwrap(), Some(5));
+        assert_eq!(find_position_of_marker_by_windows(EXAMPLE, 4), Some(5));
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(find_position_of_marker(EXAMPLE.as_bytes(), 14).unwrap(), Some(23));
+        assert_eq!(find_position_of_marker_by_windows(EXAMPLE, 14), Some(23));
+    }
+
+    #[test]
+    fn finds_markers_of_any_length() {
+        for (stream, len, position) in [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 4, 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 4, 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14, 29),
+            ("abcabcd", 1, 1),
+            ("abcabcd", 4, 7),
+        ] {
+            assert_eq!(find_position_of_marker(stream.as_bytes(), len).unwrap(), Some(position));
+            assert_eq!(find_position_of_marker_by_windows(stream, len), Some(position));
+        }
+
+        // The line break isn't part of the datastream
+        assert_eq!(find_position_of_marker("abcabc\nd".as_bytes(), 4).unwrap(), None);
+        assert_eq!(find_position_of_marker_by_windows("aaaa", 4), None);
+
+        let positions = find_positions_of_markers(EXAMPLE.as_bytes(), [PACKET_MARKER_LEN, MESSAGE_MARKER_LEN, 27]);
+        assert_eq!(positions.unwrap(), [Some(5), Some(23), None]);
+    }
+
+    #[test]
+    fn rejects_non_ascii_bytes() {
+        // Each byte of a multi-byte character would otherwise be counted as a character
+        let error = find_position_of_marker("abé".as_bytes(), 4).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "expected an ASCII character at position 3, found byte 0xc3");
+        assert!(find_positions_of_markers("abcdé".as_bytes(), [4, 5]).is_err());
+    }
+
+    #[test]
+    fn finds_every_marker() {
+        let positions = markers(EXAMPLE.as_bytes(), 4).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(positions, vec![5, 9, 13, 17, 21, 25]);
+
+        let datastream = Datastream::parse(EXAMPLE).unwrap();
+        assert_eq!(datastream.packet_markers().collect::<Vec<_>>(), positions);
+        assert_eq!(datastream.message_markers().collect::<Vec<_>>(), vec![23]);
+        let datastream = datastream.with_marker_lens(4, 5);
+        assert_eq!(datastream.message_markers().collect::<Vec<_>>(), vec![6, 13, 18, 23, 28]);
+        assert_eq!(datastream.markers(27).next(), None);
+    }
+
+    #[test]
+    fn splits_into_packets_and_messages() {
+        let datastream = Datastream::parse("aabcdaabcdeaaxyz\n").unwrap();
+        let packets = datastream.packets();
+        assert_eq!(
+            packets.iter().map(|p| (p.marker, p.content)).collect::<Vec<_>>(),
+            vec![("abcd", "a"), ("abcd", "ea"), ("axyz", "")]
+        );
+        assert_eq!((packets[1].marker_position, packets[1].content_position), (6, 10));
+        assert!(datastream.messages().is_empty());
+
+        let messages = Datastream::parse(EXAMPLE).unwrap().messages();
+        assert_eq!((messages[0].marker, messages[0].content), ("vbhsrlpgdmjqwf", "tvncz"));
+
+        let error = Datastream::parse("abcé").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+}
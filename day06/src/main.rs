@@ -1,41 +1,14 @@
-use std::collections::{HashSet, VecDeque};
-use std::fs::read_to_string;
-
-fn is_marker(buffer: &VecDeque<char>) -> bool {
-    let hashset: HashSet<_> = buffer.iter().collect();
-    hashset.len() == buffer.len()
-}
-
-fn find_position_of_marker(input: &str, len: usize) -> usize {
-    let mut buffer = VecDeque::new();
-    let mut input_chars = input.chars();
-    while buffer.len() < len {
-        buffer.push_back(input_chars.next().unwrap());
-    }
-    let mut marker_count = len;
-    while !is_marker(&buffer) {
-        buffer.pop_front();
-        buffer.push_back(input_chars.next().unwrap());
-        marker_count += 1;
-    }
-    marker_count
-}
+use aoc_common::open_input;
+use day06::{find_positions_of_markers, MESSAGE_MARKER_LEN, PACKET_MARKER_LEN};
 
 fn main() {
-    let input = read_to_string("input.txt").expect("Could not read input file");
-
-    println!("Part 1: {}", find_position_of_marker(&input, 4));
-    println!("Part 2: {}", find_position_of_marker(&input, 14));
-
-    // Second implementation, just for fun:
-    let p1 = input.as_bytes()
-        .windows(4)
-        .position(|buffer| buffer.iter().collect::<HashSet<_>>().len() == buffer.len())
-        .unwrap() + 4;
-    println!("{}", p1);
-    let p2 = input.as_bytes()
-        .windows(14)
-        .position(|buffer| buffer.iter().collect::<HashSet<_>>().len() == buffer.len())
-        .unwrap() + 14;
-    println!("{}", p2);
-}
\ No newline at end of file
+    let positions = find_positions_of_markers(open_input!(), [PACKET_MARKER_LEN, MESSAGE_MARKER_LEN])
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+
+    let describe = |position: Option<usize>| position.map_or("no marker".to_string(), |p| p.to_string());
+    println!("Part 1: {}", describe(positions[0]));
+    println!("Part 2: {}", describe(positions[1]));
+}
@@ -0,0 +1,392 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use aoc_common::ParseError;
+use aoc_common::parse::{literal, number, until};
+
+// What the transcript says about a name in a directory
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Entry {
+    Dir,
+    File(u64),
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Entry::Dir => write!(f, "a directory"),
+            Entry::File(size) => write!(f, "a file of size {}", size),
+        }
+    }
+}
+
+// Something in the transcript that can't have happened
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    // cd .. from the root
+    AboveRoot,
+    // cd into a file
+    NotADirectory(String),
+    // cd into something missing from its directory's listing
+    NoSuchDirectory(String),
+    // A listing entry that doesn't follow an ls
+    OutputWithoutLs,
+    // A name listed as one thing, having been seen as another
+    Contradiction { name: String, was: Entry, now: Entry },
+    // A listing that leaves out something already seen in the directory
+    Unlisted(String),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::AboveRoot => write!(f, "can't go up from /"),
+            Problem::NotADirectory(name) => write!(f, "can't cd into {}, which is a file", name),
+            Problem::NoSuchDirectory(name) => write!(f, "can't cd into {}, which isn't listed", name),
+            Problem::OutputWithoutLs => write!(f, "listing without an ls"),
+            Problem::Contradiction { name, was, now } => write!(f, "{} is listed as {}, but was {}", name, now, was),
+            Problem::Unlisted(name) => write!(f, "listing leaves out {}", name),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    // A line that isn't a command or a listing entry
+    Parse(ParseError),
+    // A line (numbered from 1) that's well formed, but doesn't fit with those before it
+    Transcript { line: usize, problem: Problem },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Transcript { line, problem } => write!(f, "line {}: {}", line, problem),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+pub struct FsTree {
+    nodes: Vec<FsNode>,
+    dir_size_cache: HashMap<usize, u64>,
+}
+pub enum FsNode {
+    Dir(Option<usize>, HashMap<String, usize>),
+    File(u64)
+}
+
+impl Default for FsTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FsTree {
+    pub fn new() -> FsTree {
+        FsTree {
+            nodes: vec![FsNode::Dir(None, HashMap::new())],
+            dir_size_cache: HashMap::new(),
+        }
+    }
+
+    fn add_child<F>(&mut self, parent_index: usize, name: &str, create_node: F) -> usize where F: Fn() -> FsNode {
+        let parent = self.nodes.get(parent_index);
+        if let Some(FsNode::Dir(_, children)) = parent {
+            if children.contains_key(name) {
+                return *children.get(name).unwrap();
+            }
+        } else {
+            panic!("Node {} is not Dir", parent_index);
+        }
+
+        let new_index = self.nodes.len();
+        self.nodes.push(create_node());
+        let parent = &mut self.nodes[parent_index];
+        if let FsNode::Dir(_, children) = parent {
+            children.insert(name.to_string(), new_index);
+        } else {
+            panic!("Node {} is not Dir", parent_index);
+        }
+        new_index
+    }
+
+    pub fn dir(&mut self, parent_index: usize, name: &str) -> usize {
+        self.add_child(parent_index, name, || FsNode::Dir(Some(parent_index), HashMap::new()))
+    }
+
+    pub fn file(&mut self, parent_index: usize, name: &str, size: u64) -> usize {
+        self.add_child(parent_index, name, || FsNode::File(size))
+    }
+
+    // The directory containing a directory, or None for the root
+    pub fn parent(&self, cwd: usize) -> Option<usize> {
+        if let FsNode::Dir(parent, _) = &self.nodes[cwd] {
+            *parent
+        } else {
+            panic!("Node {} is not Dir", cwd);
+        }
+    }
+
+    pub fn child(&self, dir_index: usize, name: &str) -> Option<usize> {
+        if let FsNode::Dir(_, children) = &self.nodes[dir_index] {
+            children.get(name).copied()
+        } else {
+            panic!("Node {} is not Dir", dir_index);
+        }
+    }
+
+    fn entry(&self, index: usize) -> Entry {
+        match self.nodes[index] {
+            FsNode::Dir(_, _) => Entry::Dir,
+            FsNode::File(size) => Entry::File(size),
+        }
+    }
+
+    pub fn calc_dir_size(&mut self, dir_index: usize) -> u64 {
+        if let Some(size) = self.dir_size_cache.get(&dir_index) {
+            return *size;
+        }
+
+        let mut dir_indexes = vec![];
+        if let FsNode::Dir(_, children) = &self.nodes[dir_index] {
+            let mut size = 0;
+            for &child_index in children.values() {
+                match &self.nodes[child_index] {
+                    FsNode::File(s) => size += *s,
+                    FsNode::Dir(_, _) => dir_indexes.push(child_index),
+                };
+            }
+            for child_index in dir_indexes {
+                size += self.calc_dir_size(child_index);
+            }
+            self.dir_size_cache.insert(dir_index, size);
+            size
+        } else {
+            panic!("Node {} is not Dir", dir_index);
+        }
+    }
+
+    pub fn sum_of_dirs_not_more_than(&self, limit: u64) -> u64 {
+        self.dir_size_cache.values()
+            .filter(|s| s <= &&limit)
+            .sum()
+    }
+
+    // The size of the smallest directory to delete to leave target free, which is 0 if there's
+    // already enough free, or None if even deleting everything wouldn't be enough
+    pub fn smallest_directory_freeing_up_to(&self, target: u64) -> Option<u64> {
+        let total_size: u64 = 70000000;
+        let cur_free = total_size.saturating_sub(self.dir_size_cache[&0]);
+        let Some(min_dir_size) = target.checked_sub(cur_free).filter(|&size| size > 0) else {
+            return Some(0);
+        };
+        self.dir_size_cache.values()
+            .filter(|s| s >= &&min_dir_size)
+            .min()
+            .copied()
+    }
+}
+
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(&'a str, u64),
+}
+
+impl<'a> Line<'a> {
+    // Parses line, a slice of input
+    fn parse(input: &str, line: &'a str) -> Result<Line<'a>, ParseError> {
+        if let Some(command) = line.strip_prefix("$ ") {
+            if command == "ls" {
+                return Ok(Line::Ls);
+            }
+            let path = literal(input, command, "cd ")?;
+            if path.is_empty() {
+                return Err(ParseError::at(input, path, "a path"));
+            }
+            Ok(Line::Cd(path))
+        } else if let Some(name) = line.strip_prefix("dir ") {
+            Ok(Line::Dir(name))
+        } else {
+            let (size, name) = until(input, line, " ")?;
+            Ok(Line::File(name, number(input, size)?))
+        }
+    }
+}
+
+// The directory being listed, and the names listed in it so far
+struct Listing<'a> {
+    dir: usize,
+    line: usize,
+    names: HashSet<&'a str>,
+}
+
+struct Shell<'a> {
+    tree: FsTree,
+    cwd: usize,
+    // The directories whose contents are known, having been listed
+    listed: HashSet<usize>,
+    listing: Option<Listing<'a>>,
+}
+
+impl<'a> Shell<'a> {
+    // Follows a path, absolute or relative, one directory at a time. Directories that haven't been
+    // listed are assumed to exist.
+    fn cd(&mut self, path: &str) -> Result<(), Problem> {
+        let mut dir = if path.starts_with('/') { 0 } else { self.cwd };
+        for name in path.split('/').filter(|&name| !name.is_empty() && name != ".") {
+            dir = if name == ".." {
+                self.tree.parent(dir).ok_or(Problem::AboveRoot)?
+            } else {
+                match self.tree.child(dir, name) {
+                    Some(child) if self.tree.entry(child) == Entry::Dir => child,
+                    Some(_) => return Err(Problem::NotADirectory(name.to_string())),
+                    None if self.listed.contains(&dir) => return Err(Problem::NoSuchDirectory(name.to_string())),
+                    None => self.tree.dir(dir, name),
+                }
+            };
+        }
+        self.cwd = dir;
+        Ok(())
+    }
+
+    fn list(&mut self, name: &'a str, now: Entry) -> Result<(), Problem> {
+        let listing = self.listing.as_mut().ok_or(Problem::OutputWithoutLs)?;
+        listing.names.insert(name);
+        match self.tree.child(listing.dir, name).map(|child| self.tree.entry(child)) {
+            Some(was) if was != now => Err(Problem::Contradiction { name: name.to_string(), was, now }),
+            Some(_) => Ok(()),
+            None => {
+                match now {
+                    Entry::Dir => self.tree.dir(listing.dir, name),
+                    Entry::File(size) => self.tree.file(listing.dir, name, size),
+                };
+                Ok(())
+            },
+        }
+    }
+
+    // Checks the listing just ended included everything already seen in the directory
+    fn end_listing(&mut self) -> Result<(), Error> {
+        let Some(listing) = self.listing.take() else {
+            return Ok(());
+        };
+        self.listed.insert(listing.dir);
+        let FsNode::Dir(_, children) = &self.tree.nodes[listing.dir] else {
+            unreachable!("Only directories are listed");
+        };
+        let unlisted = children.keys().filter(|name| !listing.names.contains(name.as_str())).min();
+        match unlisted {
+            Some(name) => Err(Error::Transcript { line: listing.line, problem: Problem::Unlisted(name.clone()) }),
+            None => Ok(()),
+        }
+    }
+}
+
+// Replays the commands in the transcript, building up the tree of everything they list. Each listing
+// of a directory must agree with anything already seen in it.
+pub fn parse_transcript(input: &str) -> Result<FsTree, Error> {
+    let mut shell = Shell { tree: FsTree::new(), cwd: 0, listed: HashSet::new(), listing: None };
+    for (i, line) in input.lines().enumerate() {
+        let in_transcript = |problem| Error::Transcript { line: i + 1, problem };
+        match Line::parse(input, line)? {
+            Line::Cd(path) => {
+                shell.end_listing()?;
+                shell.cd(path).map_err(in_transcript)?;
+            },
+            Line::Ls => {
+                shell.end_listing()?;
+                shell.listing = Some(Listing { dir: shell.cwd, line: i + 1, names: HashSet::new() });
+            },
+            Line::Dir(name) => shell.list(name, Entry::Dir).map_err(in_transcript)?,
+            Line::File(name, size) => shell.list(name, Entry::File(size)).map_err(in_transcript)?,
+        }
+    }
+    shell.end_listing()?;
+
+    let mut tree = shell.tree;
+    tree.calc_dir_size(0);
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(parse_transcript(EXAMPLE).unwrap().sum_of_dirs_not_more_than(100000), 95437);
+    }
+
+    #[test]
+    fn example_part2() {
+        let tree = parse_transcript(EXAMPLE).unwrap();
+        assert_eq!(tree.smallest_directory_freeing_up_to(30000000), Some(24933642));
+    }
+
+    #[test]
+    fn frees_nothing_when_there_is_enough_space() {
+        let tree = parse_transcript("$ cd /\n$ ls\n100 a\n").unwrap();
+        assert_eq!(tree.smallest_directory_freeing_up_to(30000000), Some(0));
+
+        let tree = parse_transcript("$ cd /\n$ ls\n50000000 a\n").unwrap();
+        assert_eq!(tree.smallest_directory_freeing_up_to(30000000), Some(50000000));
+        assert_eq!(tree.smallest_directory_freeing_up_to(80000000), None);
+    }
+
+    fn transcript_problem(input: &str) -> (usize, Problem) {
+        match parse_transcript(input) {
+            Err(Error::Transcript { line, problem }) => (line, problem),
+            other => panic!("Expected a transcript problem, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn follows_paths() {
+        let input = "$ cd /a/b\n$ ls\n10 x\n$ cd ../../c/./d/\n$ ls\n20 y\ndir e\n$ cd /\n$ ls\ndir a\ndir c\n";
+        let mut tree = parse_transcript(input).unwrap();
+        let c = tree.child(0, "c").unwrap();
+        let d = tree.child(c, "d").unwrap();
+        assert_eq!((tree.parent(d), tree.parent(0)), (Some(c), None));
+        assert_eq!((tree.calc_dir_size(0), tree.calc_dir_size(c)), (30, 20));
+
+        // The listed directory e is empty
+        let e = tree.child(d, "e").unwrap();
+        assert_eq!(tree.calc_dir_size(e), 0);
+        assert_eq!(tree.sum_of_dirs_not_more_than(20), 10 + 10 + 20 + 20);
+    }
+
+    #[test]
+    fn rejects_impossible_transcripts() {
+        assert_eq!(transcript_problem("$ cd /\n$ cd ..\n"), (2, Problem::AboveRoot));
+        assert_eq!(transcript_problem("$ cd a/../..\n"), (1, Problem::AboveRoot));
+        assert_eq!(transcript_problem("$ ls\n5 a\n$ cd a\n"), (3, Problem::NotADirectory("a".to_string())));
+        assert_eq!(transcript_problem("$ ls\ndir a\n$ cd b\n"), (3, Problem::NoSuchDirectory("b".to_string())));
+        assert_eq!(transcript_problem("dir a\n"), (1, Problem::OutputWithoutLs));
+        assert_eq!(
+            transcript_problem("$ ls\n5 a\n$ ls\n6 a\n"),
+            (4, Problem::Contradiction { name: "a".to_string(), was: Entry::File(5), now: Entry::File(6) })
+        );
+        assert_eq!(transcript_problem("$ ls\n5 a\ndir b\n$ ls\ndir b\n"), (4, Problem::Unlisted("a".to_string())));
+        assert_eq!(transcript_problem("$ cd a\n$ cd /\n$ ls\ndir b\n"), (3, Problem::Unlisted("a".to_string())));
+
+        // Listing the same thing again is fine
+        assert!(parse_transcript("$ ls\n5 a\ndir b\n$ ls\ndir b\n5 a\n").is_ok());
+
+        let Err(Error::Parse(e)) = parse_transcript("$ cd /\n$ rm a\n") else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 3, "\"cd \""));
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_common::{Grid, ParseError};
+use aoc_common::parse::end_of;
+
+// (height, is_visible_from_edge, [left, right, down, up])
+pub type GridCell = (i8, bool, [u32; 4]);
+
+pub fn parse_grid(input: &str) -> Result<Grid<GridCell>, ParseError> {
+    let grid = Grid::try_parse(input, |c| {
+        c.to_digit(10)
+            .map(|height| (height as i8, false, [0; 4]))
+            .ok_or("a tree height (0-9)")
+    })?;
+    // Part two needs at least one tree to score
+    if grid.width() == 0 {
+        return Err(ParseError::at(input, end_of(input), "a tree height (0-9)"));
+    }
+    Ok(grid)
+}
+
+// NOTE: Original solution didn't include this function, but instead basically copied the body 4 times,
+// once for each direction
+pub fn scan_grid(
+    grid: &mut Grid<GridCell>,
+    outer: &(impl Iterator<Item = usize> + Clone),
+    inner: &(impl Iterator<Item = usize> + Clone),
+    is_row: bool,
+    dir_index: usize,
+) {
+    for i in outer.clone() {
+        let mut tallest = -1i8;
+        let mut dists_to_not_smaller = [0; 10];
+        for j in inner.clone() {
+            // Are we iterating over a row or a column?
+            let (x, y) = if is_row { (j, i) } else { (i, j) };
+
+            // Check if the tree is visible from the edge in this direction
+            if grid[(x, y)].0 > tallest {
+                grid[(x, y)].1 = true;
+                tallest = grid[(x, y)].0;
+            }
+
+            // Record the distance to the nearest tree that is >= the height of this tree in this direction
+            grid[(x, y)].2[dir_index] = dists_to_not_smaller[grid[(x, y)].0 as usize];
+
+            // Distance for trees this height or smaller reset to 1 (this is the closest tree >= their height)
+            for h in 0..=grid[(x, y)].0 {
+                dists_to_not_smaller[h as usize] = 1;
+            }
+            // Distance for taller trees increments by one (this is another tree they can see over)
+            for h in (grid[(x, y)].0+1)..=9 {
+                dists_to_not_smaller[h as usize] += 1;
+            }
+        }
+    }
+}
+
+pub fn scan_grid_all_directions(grid: &mut Grid<GridCell>) {
+    let rows = 0..grid.height();
+    let cols = 0..grid.width();
+    scan_grid(grid, &rows, &cols, true, 0);
+    scan_grid(grid, &rows, &cols.clone().rev(), true, 1);
+    scan_grid(grid, &cols.clone().rev(), &rows, false, 2);
+    scan_grid(grid, &cols.rev(), &rows.rev(), false, 3);
+}
+
+pub fn num_visible(grid: &Grid<GridCell>) -> usize {
+    grid.cells()
+        .filter(|(_, v, _)| *v)
+        .count()
+}
+
+pub fn max_scenic_score(grid: &Grid<GridCell>) -> u32 {
+    grid.cells()
+        .map(|(_, _, hs)| hs[0] * hs[1] * hs[2] * hs[3])
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let mut grid = parse_grid(EXAMPLE).unwrap();
+        scan_grid_all_directions(&mut grid);
+        assert_eq!(num_visible(&grid), 21);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut grid = parse_grid(EXAMPLE).unwrap();
+        scan_grid_all_directions(&mut grid);
+        assert_eq!(max_scenic_score(&grid), 8);
+    }
+
+    #[test]
+    fn rejects_empty_grids() {
+        for input in ["", "\n"] {
+            let error = parse_grid(input).unwrap_err();
+            assert_eq!((error.expected.as_str(), error.found.as_str()), ("a tree height (0-9)", "end of input"));
+        }
+    }
+}
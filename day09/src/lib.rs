@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+use aoc_common::{Coord, Direction, ParseError};
+use aoc_common::parse::{number, until};
+
+pub fn parse_moves(input: &str) -> Result<Vec<(Direction, u32)>, ParseError> {
+    input.lines().map(|line| {
+        let (dir, num_steps) = until(input, line, " ")?;
+        let dir = match dir {
+            "L" => Direction::West,
+            "R" => Direction::East,
+            "U" => Direction::North,
+            "D" => Direction::South,
+            _ => return Err(ParseError::at(input, dir, "a direction (L, R, U or D)")),
+        };
+        Ok((dir, number(input, num_steps)?))
+    }).collect()
+}
+
+// Each knot only follows the one before it, so the tail of a short rope traces the same path as
+// the equivalent knot of a longer rope
+pub fn count_tail_positions(moves: &[(Direction, u32)], num_knots: usize) -> usize {
+    let mut knots = vec![Coord::default(); num_knots];
+    let tail = num_knots - 1;
+
+    let mut visited = HashSet::new();
+    visited.insert(knots[tail]);
+
+    for (dir, num_steps) in moves {
+        for _ in 0..*num_steps {
+            knots[0] += dir.delta();
+
+            for i in 1..num_knots {
+                let diff = knots[i - 1] - knots[i];
+
+                if diff.x.abs() > 1 || diff.y.abs() > 1 {
+                    knots[i] += Coord::new(diff.x.signum(), diff.y.signum());
+                }
+            }
+
+            visited.insert(knots[tail]);
+        }
+    }
+
+    visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(count_tail_positions(&parse_moves(EXAMPLE).unwrap(), 2), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(count_tail_positions(&parse_moves(EXAMPLE).unwrap(), 10), 1);
+    }
+
+    #[test]
+    fn reports_bad_moves() {
+        let error = parse_moves("R x").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 3, "a number"));
+        let error = parse_moves("R 4\nQ 1").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "\"Q\""));
+    }
+}
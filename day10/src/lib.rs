@@ -0,0 +1,142 @@
+use aoc_common::ParseError;
+use aoc_common::parse::number;
+
+pub const WIDTH: usize = 40;
+pub const HEIGHT: usize = 6;
+
+pub struct CPU {
+    x: i32,
+    cycle: i32,
+    pub signal_strength: i32,
+    screen: [[bool; WIDTH]; HEIGHT],
+}
+
+impl Default for CPU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CPU {
+    pub fn new() -> CPU {
+        CPU {
+            x: 1,
+            cycle: 0,
+            signal_strength: 0,
+            screen: [[false; WIDTH]; HEIGHT],
+        }
+    }
+
+    fn draw_pixel(&mut self) {
+        let y = (self.cycle / WIDTH as i32) as usize;
+        let x = (self.cycle % WIDTH as i32) as usize;
+        self.screen[y][x] = self.x.abs_diff(x as i32) <= 1;
+    }
+
+    pub fn render_screen(&self) -> String {
+        let mut result = String::new();
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                result.push(if self.screen[y][x] { '#' } else { '.' });
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    pub fn print_screen(&self) {
+        print!("{}", self.render_screen());
+    }
+
+    fn tick(&mut self) {
+        self.draw_pixel();
+        self.cycle += 1;
+
+        if self.cycle % 40 == 20 {
+            self.signal_strength += self.x * self.cycle;
+        }
+    }
+
+    pub fn noop(&mut self) {
+        self.tick();
+    }
+
+    pub fn addx(&mut self, v: i32) {
+        self.tick();
+        self.tick();
+        self.x += v;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+// Parses the program, which must finish within the cycles it takes to draw the screen
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut program = vec![];
+    let mut cycles = 0;
+    for line in input.lines() {
+        let instruction = if line == "noop" {
+            Instruction::Noop
+        } else {
+            let v = line.strip_prefix("addx ")
+                .ok_or_else(|| ParseError::at(input, line, "\"noop\" or \"addx\""))?;
+            Instruction::Addx(number(input, v)?)
+        };
+        cycles += instruction.cycles();
+        if cycles > WIDTH * HEIGHT {
+            let expected = format!("the end of the program within {} cycles", WIDTH * HEIGHT);
+            return Err(ParseError::at(input, line, expected));
+        }
+        program.push(instruction);
+    }
+    Ok(program)
+}
+
+pub fn run_program(program: &[Instruction]) -> CPU {
+    let mut cpu = CPU::new();
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => cpu.noop(),
+            Instruction::Addx(v) => cpu.addx(*v),
+        }
+    }
+    cpu
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(run_program(&parse_program(EXAMPLE).unwrap()).signal_strength, 13140);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            run_program(&parse_program(EXAMPLE).unwrap()).render_screen().trim_end(),
+            "##..##..##..##..##..##..##..##..##..##..\n\
+             ###...###...###...###...###...###...###.\n\
+             ####....####....####....####....####....\n\
+             #####.....#####.....#####.....#####.....\n\
+             ######......######......######......####\n\
+             #######.......#######.......#######....."
+        );
+    }
+}
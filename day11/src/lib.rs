@@ -0,0 +1,144 @@
+use std::collections::BinaryHeap;
+use aoc_common::ParseError;
+use aoc_common::parse::{end_of, literal, number, until};
+
+#[derive(Clone)]
+pub enum WorryOp {
+    Add(u64),
+    Times(u64),
+    Double,
+    Square,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub op: WorryOp,
+    pub test: (u64, usize, usize),
+    pub inspections: u64,
+}
+
+pub fn calc_monkey_business(monkeys: &mut [Monkey], rounds: usize, worry_reducer: impl Fn(u64) -> u64) -> u64 {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for j in 0..monkeys[i].items.len() {
+                let monkey = &mut monkeys[i];
+                let mut worry = monkey.items[j];
+                worry = match monkey.op {
+                    WorryOp::Add(n) => worry + n,
+                    WorryOp::Times(n) => worry *n,
+                    WorryOp::Double => worry + worry,
+                    WorryOp::Square => worry * worry
+                };
+                worry = worry_reducer(worry);
+                let recipient = if worry.is_multiple_of(monkey.test.0) {
+                    monkey.test.1
+                } else {
+                    monkey.test.2
+                };
+
+                monkeys[recipient].items.push(worry);
+            }
+            monkeys[i].inspections += monkeys[i].items.len() as u64;
+            monkeys[i].items.clear();
+        }
+    }
+
+    let inspections: BinaryHeap<u64> = monkeys.iter().map(|m| m.inspections).collect();
+    inspections.iter().take(2).product()
+}
+
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut lines = input.lines();
+    let mut monkeys = vec![];
+    let mut targets = vec![];
+    while let Some(header) = lines.next() {
+        literal(input, header, "Monkey ")?;
+        let mut next_line = || lines.next()
+            .ok_or_else(|| ParseError::at(input, end_of(input), "the rest of the monkey's notes"));
+
+        let items = literal(input, next_line()?, "  Starting items: ")?
+            .split(", ")
+            .map(|n| number(input, n))
+            .collect::<Result<_, _>>()?;
+        let (operator, operand) = until(input, literal(input, next_line()?, "  Operation: new = old ")?, " ")?;
+        let op = match (operator, operand) {
+            ("+", "old") => WorryOp::Double,
+            ("*", "old") => WorryOp::Square,
+            ("+", n) => WorryOp::Add(number(input, n)?),
+            ("*", n) => WorryOp::Times(number(input, n)?),
+            _ => return Err(ParseError::at(input, operator, "\"+\" or \"*\"")),
+        };
+        let test_div_token = literal(input, next_line()?, "  Test: divisible by ")?;
+        let test_div = number(input, test_div_token)?;
+        if test_div == 0 {
+            return Err(ParseError::at(input, test_div_token, "a divisor greater than 0"));
+        }
+        let if_true_token = literal(input, next_line()?, "    If true: throw to monkey ")?;
+        let if_false_token = literal(input, next_line()?, "    If false: throw to monkey ")?;
+        let test = (test_div, number(input, if_true_token)?, number(input, if_false_token)?);
+        targets.extend([(if_true_token, test.1), (if_false_token, test.2)]);
+        monkeys.push(Monkey { items, op, test, inspections: 0 });
+        lines.next();
+    }
+
+    // Monkeys can only throw to each other once all of them are known
+    if let Some((token, _)) = targets.iter().find(|(_, target)| *target >= monkeys.len()) {
+        return Err(ParseError::at(input, token, format!("a monkey below {}", monkeys.len())));
+    }
+    Ok(monkeys)
+}
+
+pub fn test_product(monkeys: &[Monkey]) -> u64 {
+    monkeys.iter().map(|m| m.test.0).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let mut monkeys = parse_monkeys(EXAMPLE).unwrap();
+        assert_eq!(calc_monkey_business(&mut monkeys, 20, |w| w / 3), 10605);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut monkeys = parse_monkeys(EXAMPLE).unwrap();
+        let test_product = test_product(&monkeys);
+        assert_eq!(calc_monkey_business(&mut monkeys, 10_000, |w| w % test_product), 2713310158);
+    }
+
+    #[test]
+    fn rejects_bad_tests() {
+        let notes = |divisor, if_true| format!(
+            "Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey 0\n",
+            divisor, if_true
+        );
+        let Err(error) = parse_monkeys(&notes(0, 0)) else { panic!("Divisible by 0 should be rejected") };
+        assert_eq!((error.line, error.column, error.expected.as_str()), (4, 22, "a divisor greater than 0"));
+        let Err(error) = parse_monkeys(&notes(23, 5)) else { panic!("Monkey 5 should be rejected") };
+        assert_eq!((error.line, error.column, error.expected.as_str()), (5, 30, "a monkey below 1"));
+        assert!(parse_monkeys(&notes(23, 0)).is_ok());
+    }
+
+    #[test]
+    fn doubles_old_plus_old() {
+        let notes = |operation| format!(
+            "Monkey 0:\n  Starting items: 5\n  Operation: new = {}\n  Test: divisible by 2\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n",
+            operation
+        );
+        for (operation, worry) in [("old + old", 10), ("old * old", 25)] {
+            let mut monkeys = parse_monkeys(&notes(operation)).unwrap();
+            let inspected = Cell::new(0);
+            calc_monkey_business(&mut monkeys, 1, |w| { inspected.set(w); w });
+            assert_eq!(inspected.get(), worry, "{}", operation);
+        }
+        let Err(error) = parse_monkeys(&notes("old - old")) else { panic!("\"-\" should be rejected") };
+        assert_eq!((error.line, error.column), (3, 24));
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use aoc_common::{Coord, Grid, ParseError};
+use aoc_common::parse::end_of;
+
+#[derive(Eq, Clone)]
+pub struct GridCell {
+    pub coord: Coord,
+    pub height: u8,
+    pub dist: i32,
+}
+
+impl PartialEq<Self> for GridCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+impl Ord for GridCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Order GridCell by greatest dist first
+        other.dist.cmp(&self.dist)
+    }
+}
+impl PartialOrd<Self> for GridCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn calculate_shortest_paths(grid: &mut Grid<GridCell>, start: &Coord) {
+    let mut queue = BinaryHeap::new();
+
+    grid[*start].dist = 0;
+    queue.push(grid[*start].clone());
+
+    while let Some(cell) = queue.pop() {
+        for coord in cell.coord.neighbours() {
+            if let Some(neighbour) = grid.get_mut(&coord) {
+                if neighbour.height + 1 >= cell.height
+                    && (neighbour.dist == -1 || cell.dist + 1 < neighbour.dist)
+                {
+                    neighbour.dist = cell.dist + 1;
+                    queue.push(neighbour.clone());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Heightmap {
+    pub grid: Grid<GridCell>,
+    pub start: Coord,
+    pub end: Coord,
+    pub poss_starts: Vec<Coord>,
+}
+
+impl Heightmap {
+    pub fn parse(input: &str) -> Result<Heightmap, ParseError> {
+        let chars = Grid::try_parse(input, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            _ => Err("a height (a-z), S or E"),
+        })?;
+        let start = chars.position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::at(input, end_of(input), "a start (S)"))?;
+        let end = chars.position(|&c| c == 'E')
+            .ok_or_else(|| ParseError::at(input, end_of(input), "an end (E)"))?;
+        let poss_starts = chars.iter()
+            .filter(|(_, &c)| c == 'a')
+            .map(|(coord, _)| coord)
+            .collect();
+
+        let mut grid = chars.map(|&c| {
+            let height = match c {
+                'S' => 0,
+                'E' => 25,
+                _ => c as u8 - b'a',
+            };
+            GridCell { height, dist: -1, coord: Coord::default() }
+        });
+        for coord in grid.coords().collect::<Vec<_>>() {
+            grid[coord].coord = coord;
+        }
+
+        Ok(Heightmap { grid, start, end, poss_starts })
+    }
+
+    // Find all shortest paths, starting from the end point and running backwards
+    pub fn calculate_shortest_paths(&mut self) {
+        calculate_shortest_paths(&mut self.grid, &self.end);
+    }
+
+    pub fn shortest_path_from_start(&self) -> i32 {
+        self.grid[self.start].dist
+    }
+
+    pub fn shortest_path_from_any_lowest(&self) -> i32 {
+        self.poss_starts.iter()
+            .map(|&coord| self.grid[coord].dist)
+            .filter(|d| d > &0)
+            .min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let mut heightmap = Heightmap::parse(EXAMPLE).unwrap();
+        heightmap.calculate_shortest_paths();
+        assert_eq!(heightmap.shortest_path_from_start(), 31);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut heightmap = Heightmap::parse(EXAMPLE).unwrap();
+        heightmap.calculate_shortest_paths();
+        assert_eq!(heightmap.shortest_path_from_any_lowest(), 29);
+    }
+}
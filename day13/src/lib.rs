@@ -0,0 +1,125 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::CharIndices;
+use aoc_common::ParseError;
+use aoc_common::parse::number;
+
+#[derive(Clone, Eq, PartialEq)]
+pub enum PacketNode {
+    Int(u8),
+    List(Vec<PacketNode>)
+}
+
+type Chars<'a> = Peekable<CharIndices<'a>>;
+
+fn parse_packet(input: &str, chars: &mut Chars) -> Result<PacketNode, ParseError> {
+    match chars.peek() {
+        Some((_, '[')) => {
+            chars.next();
+            parse_packet_list(input, chars)
+        },
+        Some((_, c)) if c.is_ascii_digit() => parse_packet_int(input, chars),
+        _ => Err(unexpected(input, chars, "\"[\" or a number")),
+    }
+}
+
+fn parse_packet_list(input: &str, chars: &mut Chars) -> Result<PacketNode, ParseError> {
+    use PacketNode::*;
+    let mut items = vec![];
+    loop {
+        match chars.peek() {
+            Some((_, ',')) | Some((_, ' ')) => { chars.next(); },
+            Some((_, ']')) => break,
+            Some((_, '\n')) | None => return Err(unexpected(input, chars, "\"]\"")),
+            Some(_) => { items.push(parse_packet(input, chars)?); },
+        }
+    }
+    chars.next();
+    Ok(List(items))
+}
+
+fn parse_packet_int(input: &str, chars: &mut Chars) -> Result<PacketNode, ParseError> {
+    use PacketNode::*;
+    let start = chars.peek().map_or(input.len(), |&(i, _)| i);
+    let mut end = start;
+    while let Some((i, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        end = i + 1;
+    }
+    Ok(Int(number(input, &input[start..end])?))
+}
+
+fn unexpected(input: &str, chars: &mut Chars, expected: &str) -> ParseError {
+    match chars.peek() {
+        Some(&(i, c)) => ParseError::at_offset(input, i, c.len_utf8(), expected),
+        None => ParseError::at_offset(input, input.len(), 0, expected),
+    }
+}
+
+impl PartialOrd<Self> for PacketNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use PacketNode::*;
+        match (self, other) {
+            (Int(l), Int(r)) => l.cmp(r),
+            (List(ls), List(rs)) => ls.cmp(rs),
+            (List(_), Int(_)) => self.cmp(&List(vec![other.clone()])),
+            (Int(_), List(_)) => List(vec![self.clone()]).cmp(other),
+        }
+    }
+}
+
+pub fn parse_packets(input: &str) -> Result<Vec<PacketNode>, ParseError> {
+    let mut result = vec![];
+    let mut chars = input.char_indices().peekable();
+    loop {
+        match chars.peek() {
+            Some((_, '\n')) | Some((_, '\r')) => { chars.next(); },
+            Some(_) => { result.push(parse_packet(input, &mut chars)?); },
+            None => break,
+        }
+    }
+    Ok(result)
+}
+
+pub fn sum_of_ordered_pair_indices(packets: &[PacketNode]) -> usize {
+    packets.chunks_exact(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] < pair[1])
+        .map(|(i, _)| i + 1)
+        .sum::<usize>()
+}
+
+pub fn decoder_key(packets: &[PacketNode]) -> usize {
+    let mut packets = packets.to_vec();
+    let div1 = PacketNode::List(vec![PacketNode::List(vec![PacketNode::Int(2)])]);
+    let div2 = PacketNode::List(vec![PacketNode::List(vec![PacketNode::Int(6)])]);
+    packets.push(div1.clone());
+    packets.push(div2.clone());
+    packets.sort();
+
+    let pos1 = packets.iter().position(|p| p == &div1).unwrap() + 1;
+    let pos2 = packets.iter().position(|p| p == &div2).unwrap() + 1;
+    pos1 * pos2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(sum_of_ordered_pair_indices(&parse_packets(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(decoder_key(&parse_packets(EXAMPLE).unwrap()), 140);
+    }
+}
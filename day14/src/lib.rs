@@ -0,0 +1,99 @@
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use aoc_common::ParseError;
+use aoc_common::parse::{number, until};
+
+#[derive(Clone)]
+pub enum Substance {
+    Rock,
+    Sand
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    map: HashMap<(usize, usize), Substance>,
+    max_y: usize,
+}
+
+impl Cave {
+    // Parses paths of rock, each a list of points joined by horizontal or vertical lines
+    pub fn parse(input: &str) -> Result<Cave, ParseError> {
+        use Substance::*;
+
+        let mut map = HashMap::new();
+        let mut max_y = 0;
+        for line in input.lines() {
+            let mut prev = None;
+            for point in line.split(" -> ") {
+                let (x, y) = until(input, point, ",")?;
+                let cur: (usize, usize) = (number(input, x)?, number(input, y)?);
+                let Some(prev) = prev.replace(cur) else {
+                    max_y = max(max_y, cur.1);
+                    continue;
+                };
+                if prev.0 != cur.0 && prev.1 != cur.1 {
+                    return Err(ParseError::at(input, point, "a point in line with the one before"));
+                }
+                for x in min(prev.0, cur.0)..=max(prev.0, cur.0) {
+                    for y in min(prev.1, cur.1)..=max(prev.1, cur.1) {
+                        map.insert((x, y), Rock);
+                    }
+                }
+                max_y = max(max_y, cur.1);
+            }
+        }
+        Ok(Cave { map, max_y })
+    }
+
+    // Pours sand until it first falls past the lowest rock (if stop_at_abyss is set) or until the
+    // source is blocked, and returns the number of units of sand that came to rest
+    pub fn pour_sand(&mut self, stop_at_abyss: bool) -> usize {
+        let map = &mut self.map;
+        let max_y = self.max_y;
+
+        let mut sand_count = 0;
+        let mut path = vec![];
+        path.push((500, 0));
+        loop {
+            let sand_pos = path.last().unwrap();
+            let new_pos = match (
+                map.contains_key(&(sand_pos.0 - 1, sand_pos.1 + 1)) || sand_pos.1 > max_y,
+                map.contains_key(&(sand_pos.0, sand_pos.1 + 1)) || sand_pos.1 > max_y,
+                map.contains_key(&(sand_pos.0 + 1, sand_pos.1 + 1)) || sand_pos.1 > max_y
+            ) {
+                (_, false, _) => Some((sand_pos.0, sand_pos.1 + 1)),
+                (false, _, _) => Some((sand_pos.0 - 1, sand_pos.1 + 1)),
+                (_, _, false) => Some((sand_pos.0 + 1, sand_pos.1 + 1)),
+                (true, true, true) => { map.insert(*sand_pos, Substance::Sand); sand_count += 1; None }
+            };
+            if let Some(p) = new_pos {
+                path.push(p);
+                if stop_at_abyss && p.1 > max_y {
+                    return sand_count;
+                }
+            } else {
+                path.pop();
+            }
+            if path.is_empty() {
+                return sand_count;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(Cave::parse(EXAMPLE).unwrap().pour_sand(true), 24);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(Cave::parse(EXAMPLE).unwrap().pour_sand(false), 93);
+    }
+}
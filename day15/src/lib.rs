@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+use std::iter::from_fn;
+use std::ops::RangeInclusive;
+use rayon::prelude::*;
+use range_union_find::IntRangeUnionFind;
+use aoc_common::{Coord, ParseError};
+use aoc_common::parse::{literal, number, until};
+
+pub struct Sensor {
+    pub coord: Coord,
+    pub nearest_beacon: Coord,
+    pub dist: usize,
+}
+
+impl Sensor {
+    // Parses a line of input, a slice of input
+    pub fn parse(input: &str, line: &str) -> Result<Sensor, ParseError> {
+        let rest = literal(input, line, "Sensor at x=")?;
+        let (sx, rest) = until(input, rest, ", y=")?;
+        let (sy, rest) = until(input, rest, ": closest beacon is at x=")?;
+        let (bx, by) = until(input, rest, ", y=")?;
+        let coord = Coord::new(number(input, sx)?, number(input, sy)?);
+        let nearest_beacon = Coord::new(number(input, bx)?, number(input, by)?);
+        let dist = coord.manhattan_dist(&nearest_beacon);
+        Ok(Sensor { coord, nearest_beacon, dist })
+    }
+
+    pub fn covered_range_at(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let dy = self.coord.y.abs_diff(y);
+        let dx = self.dist as isize - dy as isize;
+        if dx >= 0 {
+            Some((self.coord.x-dx)..=(self.coord.x+dx))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, point: &Coord) -> bool {
+        self.coord.manhattan_dist(point) <= self.dist
+    }
+
+    pub fn border_iter(&self) -> impl Iterator<Item = Coord> + '_ {
+        let border_dist = (self.dist + 1) as isize;
+        let left = Coord::new(self.coord.x - border_dist, self.coord.y);
+        let right = Coord::new(self.coord.x + border_dist, self.coord.y);
+        let top = Coord::new(self.coord.x, self.coord.y - border_dist);
+        let bottom = Coord::new(self.coord.x, self.coord.y + border_dist);
+
+        let left_to_top = coord_iterator(left, top, 1, -1);
+        let top_to_right = coord_iterator(top, right, 1, 1);
+        let right_to_bottom = coord_iterator(right, bottom, -1, 1);
+        let bottom_to_left = coord_iterator(bottom, left, -1, -1);
+
+        left_to_top.chain(top_to_right).chain(right_to_bottom).chain(bottom_to_left)
+    }
+}
+
+fn coord_iterator(start: Coord, end: Coord, dx: isize, dy: isize) -> impl Iterator<Item = Coord> {
+    let mut next = start;
+    from_fn(move || {
+        if next == end {
+            None
+        } else {
+            let result = Some(next);
+            next = Coord::new(next.x + dx, next.y + dy);
+            result
+        }
+    })
+}
+
+pub fn parse_sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    input.lines().map(|line| Sensor::parse(input, line)).collect()
+}
+
+pub fn count_positions_without_beacon(sensors: &[Sensor], target_row: isize) -> isize {
+    let beacons: HashSet<_> = sensors.iter().map(|s| s.nearest_beacon).collect();
+    let unioned_ranges = sensors.iter()
+        .filter_map(|s| s.covered_range_at(target_row))
+        .fold(IntRangeUnionFind::new(), |mut acc, r| { acc.insert_range(&r).unwrap(); acc });
+    let total_coverage = unioned_ranges
+        .into_collection::<Vec<_>>()
+        .into_iter()
+        .map(|r| r.end() - r.start() + 1)
+        .sum::<isize>();
+    let num_beacons = beacons.iter().filter(|b| b.y == target_row).count() as isize;
+    total_coverage - num_beacons
+}
+
+pub fn find_distress_beacon(sensors: &[Sensor], search_max: isize) -> Option<Coord> {
+    // Scan the sensors' borders in parallel, but keep the find from the earliest sensor so the
+    // answer is the same as scanning them in order
+    sensors.par_iter().find_map_first(|sensor| {
+        sensor.border_iter().find(|border_coord| {
+            let in_search_bounds = border_coord.x >= 0 && border_coord.x <= search_max &&
+                border_coord.y >= 0 && border_coord.y <= search_max;
+            in_search_bounds && !sensors.iter().any(|s| s.contains(border_coord))
+        })
+    })
+}
+
+pub fn tuning_frequency(beacon: &Coord) -> isize {
+    beacon.x * 4000000 + beacon.y
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(count_positions_without_beacon(&parse_sensors(EXAMPLE).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn example_part2() {
+        let beacon = find_distress_beacon(&parse_sensors(EXAMPLE).unwrap(), 20).unwrap();
+        assert_eq!(tuning_frequency(&beacon), 56000011);
+    }
+}
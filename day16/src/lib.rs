@@ -0,0 +1,4 @@
+// Two approaches to the same puzzle: the original recursive search, and a faster version that
+// precalculates the best pressure release for every combination of opened valves
+pub mod recursive;
+pub mod precalc;
@@ -0,0 +1,215 @@
+use std::collections::{HashMap, VecDeque};
+use aoc_common::ParseError;
+use aoc_common::parse::{end_of, literal, number, until};
+
+pub struct Valve {
+    pub rate: usize,
+    pub neighbours: Vec<String>,
+}
+
+#[derive(Hash, Eq, PartialEq, Clone)]
+pub struct BitValves(u64);
+
+impl Default for BitValves {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitValves {
+    pub fn new() -> BitValves {
+        BitValves(0)
+    }
+
+    pub fn is_open(&self, valve_index: usize) -> bool {
+        self.0 & (1 << valve_index) > 0
+    }
+
+    pub fn open(&self, valve_index: usize) -> BitValves {
+        BitValves(self.0 | (1 << valve_index))
+    }
+
+    pub fn disjoint_with(&self, other: &Self) -> bool {
+        self.0 & other.0 == 0
+    }
+}
+
+pub fn parse_valves(input: &str) -> Result<(Vec<Valve>, HashMap<String, usize>), ParseError> {
+    let mut valve_index_by_name = HashMap::new();
+    let mut neighbour_names = vec![];
+    let valves = input.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (name, rest) = until(input, literal(input, line, "Valve ")?, " ")?;
+            let (rate, rest) = until(input, literal(input, rest, "has flow rate=")?, "; ")?;
+            let rate = number(input, rate)?;
+            let names = literal(input, rest, "tunnels lead to valves ")
+                .or_else(|_| literal(input, rest, "tunnel leads to valve "))?
+                .split(", ")
+                .collect::<Vec<_>>();
+            let neighbours = names.iter().map(|s| s.to_string()).collect();
+            neighbour_names.extend(names);
+            valve_index_by_name.insert(name.to_string(), i);
+            Ok(Valve { rate, neighbours })
+        })
+        .collect::<Result<Vec<Valve>, ParseError>>()?;
+    if let Some(unknown) = neighbour_names.iter().find(|&&n| !valve_index_by_name.contains_key(n)) {
+        return Err(ParseError::at(input, unknown, "the name of a valve"));
+    }
+    // Everything starts from valve AA
+    if !valve_index_by_name.contains_key("AA") {
+        return Err(ParseError::at(input, end_of(input), "a valve named AA"));
+    }
+    Ok((valves, valve_index_by_name))
+}
+
+pub fn calculate_all_shortest_distances(valves: &[Valve], valve_index_by_name: &HashMap<String, usize>) -> Vec<Vec<usize>> {
+    // Floyd-Warshall to calculate min dists between all valves
+    let mut dists = vec![vec![usize::MAX; valves.len()]; valves.len()];
+    for (i, valve) in valves.iter().enumerate() {
+        for n in &valve.neighbours {
+            let j = &valve_index_by_name[n];
+            dists[i][*j] = 1;
+        }
+        dists[i][i] = 0;
+    }
+    for k in 0..valves.len() {
+        for i in 0..valves.len() {
+            for j in 0..valves.len() {
+                if dists[i][j] > dists[i][k].saturating_add(dists[k][j]) {
+                    dists[i][j] = dists[i][k].saturating_add(dists[k][j]);
+                }
+            }
+        }
+    }
+    dists
+}
+
+pub fn usable_valve_indexes(valves: &[Valve]) -> Vec<usize> {
+    valves.iter()
+        .enumerate()
+        .filter(|(_, v)| v.rate > 0)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+pub fn max_pressure_release_for_all_combinations(
+    valves: &[Valve],
+    usable_valve_indexes: &[usize],
+    start_valve_index: usize,
+    min_dists: &[Vec<usize>],
+    mins_allowed: usize
+) -> HashMap<BitValves, usize> {
+    let mut result = HashMap::new();
+
+    struct SearchState { valve: usize, mins_remaining: usize, open_valves: BitValves, pressure: usize }
+
+    let mut queue = VecDeque::new();
+    queue.push_back(SearchState {
+        valve: start_valve_index,
+        mins_remaining: mins_allowed,
+        open_valves: BitValves::new(),
+        pressure: 0
+    });
+
+    while let Some(SearchState { valve, mins_remaining, open_valves, pressure }) = queue.pop_back() {
+        // If this is the best pressure released for this set of open valves we've seen so far, store it in result
+        let cur_best = *result.get(&open_valves).unwrap_or(&0);
+        let new_best = cur_best.max(pressure);
+        result.insert(open_valves.clone(), new_best);
+
+        // Find all the closed valves we still have time to go to and open
+        let available_valves_and_dists = usable_valve_indexes.iter()
+            .filter(|i| !open_valves.is_open(**i))
+            .map(|i| (i, min_dists[valve][*i]))
+            .filter(|(_, d)| mins_remaining > *d);
+
+        for (&next_valve, dist) in available_valves_and_dists {
+            let new_mins_remaining = mins_remaining - dist - 1;
+            let state = SearchState {
+                valve: next_valve,
+                mins_remaining: new_mins_remaining,
+                open_valves: open_valves.open(next_valve),
+                pressure: pressure + valves[next_valve].rate * new_mins_remaining,
+            };
+            queue.push_back(state);
+        }
+    }
+
+    result
+}
+
+pub fn max_pressure_release(
+    valves: &[Valve],
+    usable_valve_indexes: &[usize],
+    start_valve_index: usize,
+    min_dists: &[Vec<usize>],
+    mins_allowed: usize
+) -> usize {
+    let max_releases_by_valves = max_pressure_release_for_all_combinations(
+        valves,
+        usable_valve_indexes,
+        start_valve_index,
+        min_dists,
+        mins_allowed,
+    );
+    *max_releases_by_valves.values().max().unwrap()
+}
+
+pub fn max_pressure_release_by_two_agents(
+    valves: &[Valve],
+    usable_valve_indexes: &[usize],
+    start_valve_index: usize,
+    min_dists: &[Vec<usize>],
+    mins_allowed: usize
+) -> usize {
+    let max_released_by_valves = max_pressure_release_for_all_combinations(
+        valves,
+        usable_valve_indexes,
+        start_valve_index,
+        min_dists,
+        mins_allowed,
+    );
+    let mut max_combined = 0;
+    for (subset_one_valves, subset_one_pressure) in &max_released_by_valves {
+        for (subset_two_valves, subset_two_pressure) in &max_released_by_valves {
+            if subset_one_valves.disjoint_with(subset_two_valves) {
+                max_combined = max_combined.max(subset_one_pressure + subset_two_pressure);
+            }
+        }
+    }
+    max_combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let (valves, valve_index_by_name) = parse_valves(EXAMPLE).unwrap();
+        let min_dists = calculate_all_shortest_distances(&valves, &valve_index_by_name);
+        let usable_valve_indexes = usable_valve_indexes(&valves);
+        let max = max_pressure_release(&valves, &usable_valve_indexes, valve_index_by_name["AA"], &min_dists, 30);
+        assert_eq!(max, 1651);
+    }
+
+    #[test]
+    fn example_part2() {
+        let (valves, valve_index_by_name) = parse_valves(EXAMPLE).unwrap();
+        let min_dists = calculate_all_shortest_distances(&valves, &valve_index_by_name);
+        let usable_valve_indexes = usable_valve_indexes(&valves);
+        let max = max_pressure_release_by_two_agents(&valves, &usable_valve_indexes, valve_index_by_name["AA"], &min_dists, 26);
+        assert_eq!(max, 1707);
+    }
+
+    #[test]
+    fn requires_valve_aa() {
+        let Err(error) = parse_valves("Valve BB has flow rate=0; tunnel leads to valve BB\n") else {
+            panic!("Parsed without valve AA");
+        };
+        assert_eq!((error.line, error.expected.as_str()), (2, "a valve named AA"));
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use aoc_common::ParseError;
+use aoc_common::parse::{end_of, literal, number, until};
+
+pub struct Valve {
+    pub rate: usize,
+    pub neighbours: Vec<String>,
+}
+
+pub fn is_open(open_valves: u64, valve_index: usize) -> bool {
+    open_valves & (1 << valve_index) > 0
+}
+
+pub fn open(open_valves: u64, valve_index: usize) -> u64 {
+    open_valves | (1 << valve_index)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn max_released_pressure_solo(
+    valve_index: usize,
+    mins_remaining: u16,
+    pressure_relieved: usize,
+    open_valves: u64,
+    all_open: u64,
+    valves: &[Valve],
+    usable_valve_indexes: &[usize],
+    dists: &[Vec<usize>],
+) -> usize {
+    if mins_remaining == 0 {
+        return pressure_relieved;
+    }
+    if open_valves == all_open {
+        return pressure_relieved;
+    }
+
+    let max = usable_valve_indexes.iter()
+        .filter(|&&i|
+            i != valve_index &&
+                dists[valve_index][i] < mins_remaining as usize &&
+                !is_open(open_valves, i)
+        )
+        .map(|&new_valve_index| {
+            let time_to_move_and_open = dists[valve_index][new_valve_index] + 1;
+            let new_time_remaining = mins_remaining - time_to_move_and_open as u16;
+            max_released_pressure_solo(
+                new_valve_index,
+                new_time_remaining,
+                pressure_relieved + valves[new_valve_index].rate * new_time_remaining as usize,
+                open(open_valves, new_valve_index),
+                all_open,
+                valves,
+                usable_valve_indexes,
+                dists
+            )
+        })
+        .max();
+    max.unwrap_or(pressure_relieved)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn max_released_pressure_duo(
+    my_valve_index: usize,
+    el_valve_index: usize,
+    my_mins_remaining: u16,
+    el_mins_remaining: u16,
+    pressure_relieved: usize,
+    open_valves: u64,
+    all_open: u64,
+    valves: &[Valve],
+    usable_valve_indexes: &[usize],
+    dists: &[Vec<usize>],
+    cache: &mut HashMap<(usize, usize, u16, u16, u64), usize>,
+) -> usize {
+    if my_mins_remaining == 0 && el_mins_remaining == 0 {
+        return pressure_relieved;
+    }
+    if open_valves == all_open {
+        return pressure_relieved;
+    }
+
+    let cache_key = (my_valve_index, el_valve_index, my_mins_remaining, el_mins_remaining, open_valves);
+    if let Some(&prev_pressure_relieved) = cache.get(&cache_key) {
+        if prev_pressure_relieved >= pressure_relieved {
+            return 0;
+        }
+    }
+    cache.insert(cache_key, pressure_relieved);
+
+    let mut max = pressure_relieved;
+
+    // Only I move
+    let my_move_max = usable_valve_indexes.iter()
+        .filter(|&&i|
+            i != my_valve_index &&
+                dists[my_valve_index][i] < my_mins_remaining as usize &&
+                !is_open(open_valves, i)
+        )
+        .map(|&my_new_valve_index| {
+            let time_to_move_and_open = dists[my_valve_index][my_new_valve_index] + 1;
+            let my_new_time_remaining = my_mins_remaining - time_to_move_and_open as u16;
+            max_released_pressure_duo(
+                my_new_valve_index,
+                el_valve_index,
+                my_new_time_remaining,
+                el_mins_remaining,
+                pressure_relieved + valves[my_new_valve_index].rate * my_new_time_remaining as usize,
+                open(open_valves, my_new_valve_index),
+                all_open,
+                valves,
+                usable_valve_indexes,
+                dists,
+                cache
+            )
+        })
+        .max();
+    if let Some(m) = my_move_max {
+        max = max.max(m);
+    }
+
+    // Only elephant moves
+    let el_move_max = usable_valve_indexes.iter()
+        .filter(|&&i|
+            i != el_valve_index &&
+                dists[el_valve_index][i] < el_mins_remaining as usize &&
+                !is_open(open_valves, i)
+        )
+        .map(|&el_new_valve_index| {
+            let time_to_move_and_open = dists[el_valve_index][el_new_valve_index] + 1;
+            let el_new_time_remaining = el_mins_remaining - time_to_move_and_open as u16;
+            max_released_pressure_duo(
+                my_valve_index,
+                el_new_valve_index,
+                my_mins_remaining,
+                el_new_time_remaining,
+                pressure_relieved + valves[el_new_valve_index].rate * el_new_time_remaining as usize,
+                open(open_valves, el_new_valve_index),
+                all_open,
+                valves,
+                usable_valve_indexes,
+                dists,
+                cache
+            )
+        })
+        .max();
+    if let Some(m) = el_move_max {
+        max = max.max(m);
+    }
+
+    // Both move
+    let both_move_max = usable_valve_indexes.iter()
+        .filter(|&&i|
+            i != my_valve_index &&
+                dists[my_valve_index][i] < my_mins_remaining as usize &&
+                !is_open(open_valves, i)
+        )
+        .flat_map(|&my_new_valve_index| {
+            let my_time_to_move_and_open = dists[my_valve_index][my_new_valve_index] + 1;
+            let my_new_time_remaining = my_mins_remaining - my_time_to_move_and_open as u16;
+
+            usable_valve_indexes.iter()
+                .filter(|&&i|
+                    i != el_valve_index &&
+                        i != my_new_valve_index &&
+                        dists[el_valve_index][i] + 1 < el_mins_remaining as usize &&
+                        !is_open(open_valves, i)
+                )
+                .map(|&el_new_valve_index| {
+                    let el_time_to_move_and_open = dists[el_valve_index][el_new_valve_index] + 1;
+                    let el_new_time_remaining = el_mins_remaining - el_time_to_move_and_open as u16;
+                    max_released_pressure_duo(
+                        my_new_valve_index,
+                        el_new_valve_index,
+                        my_new_time_remaining,
+                        el_new_time_remaining,
+                        pressure_relieved + valves[my_new_valve_index].rate * my_new_time_remaining as usize + valves[el_new_valve_index].rate * el_new_time_remaining as usize,
+                        open(open(open_valves, my_new_valve_index), el_new_valve_index),
+                        all_open,
+                        valves,
+                        usable_valve_indexes,
+                        dists,
+                        cache
+                    )
+                })
+                .max()
+        })
+        .max();
+    if let Some(m) = both_move_max {
+        max = max.max(m);
+    }
+    max
+}
+
+pub fn parse_valves(input: &str) -> Result<(Vec<Valve>, HashMap<String, usize>), ParseError> {
+    let mut valve_index_by_name = HashMap::new();
+    let mut neighbour_names = vec![];
+    let valves = input.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (name, rest) = until(input, literal(input, line, "Valve ")?, " ")?;
+            let (rate, rest) = until(input, literal(input, rest, "has flow rate=")?, "; ")?;
+            let rate = number(input, rate)?;
+            let names = literal(input, rest, "tunnels lead to valves ")
+                .or_else(|_| literal(input, rest, "tunnel leads to valve "))?
+                .split(", ")
+                .collect::<Vec<_>>();
+            let neighbours = names.iter().map(|s| s.to_string()).collect();
+            neighbour_names.extend(names);
+            valve_index_by_name.insert(name.to_string(), i);
+            Ok(Valve { rate, neighbours })
+        })
+        .collect::<Result<Vec<Valve>, ParseError>>()?;
+    if let Some(unknown) = neighbour_names.iter().find(|&&n| !valve_index_by_name.contains_key(n)) {
+        return Err(ParseError::at(input, unknown, "the name of a valve"));
+    }
+    // Everything starts from valve AA
+    if !valve_index_by_name.contains_key("AA") {
+        return Err(ParseError::at(input, end_of(input), "a valve named AA"));
+    }
+    Ok((valves, valve_index_by_name))
+}
+
+pub fn calculate_all_shortest_distances(valves: &[Valve], valve_index_by_name: &HashMap<String, usize>) -> Vec<Vec<usize>> {
+    // Floyd-Warshall to calculate min dists between all valves
+    let mut dists = vec![vec![1000; valves.len()]; valves.len()];
+    for (i, valve) in valves.iter().enumerate() {
+        for n in &valve.neighbours {
+            let j = &valve_index_by_name[n];
+            dists[i][*j] = 1;
+        }
+        dists[i][i] = 0;
+    }
+    for k in 0..valves.len() {
+        for i in 0..valves.len() {
+            for j in 0..valves.len() {
+                if dists[i][j] > dists[i][k] + dists[k][j] {
+                    dists[i][j] = dists[i][k] + dists[k][j];
+                }
+            }
+        }
+    }
+    dists
+}
+
+pub fn usable_valve_indexes(valves: &[Valve]) -> Vec<usize> {
+    valves.iter()
+        .enumerate()
+        .filter(|(_, v)| v.rate > 0)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+pub fn all_open(usable_valve_indexes: &[usize]) -> u64 {
+    let mut all_open = 0;
+    for uvi in usable_valve_indexes {
+        all_open = open(all_open, *uvi);
+    }
+    all_open
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let (valves, valve_index_by_name) = parse_valves(EXAMPLE).unwrap();
+        let dists = calculate_all_shortest_distances(&valves, &valve_index_by_name);
+        let usable_valve_indexes = usable_valve_indexes(&valves);
+        let all_open = all_open(&usable_valve_indexes);
+        let start = valve_index_by_name["AA"];
+        let max = max_released_pressure_solo(start, 30, 0, 0, all_open, &valves, &usable_valve_indexes, &dists);
+        assert_eq!(max, 1651);
+    }
+
+    #[test]
+    fn example_part2() {
+        let (valves, valve_index_by_name) = parse_valves(EXAMPLE).unwrap();
+        let dists = calculate_all_shortest_distances(&valves, &valve_index_by_name);
+        let usable_valve_indexes = usable_valve_indexes(&valves);
+        let all_open = all_open(&usable_valve_indexes);
+        let start = valve_index_by_name["AA"];
+        let max = max_released_pressure_duo(
+            start, start, 26, 26, 0, 0, all_open, &valves, &usable_valve_indexes, &dists, &mut HashMap::new()
+        );
+        assert_eq!(max, 1707);
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::{HashSet, VecDeque};
+use std::ops::RangeInclusive;
+use aoc_common::{Coord3, ParseError};
+use aoc_common::parse::{end_of, number, until};
+
+
+struct Cuboid(RangeInclusive<isize>, RangeInclusive<isize>, RangeInclusive<isize>);
+
+impl Cuboid {
+    fn min_coord(&self) -> Coord3 {
+        Coord3::new(*self.0.start(), *self.1.start(), *self.2.start())
+    }
+
+    fn contains(&self, coord: &Coord3) -> bool {
+        self.0.contains(&coord.x) && self.1.contains(&coord.y) && self.2.contains(&coord.z)
+    }
+}
+
+// Find the bounding box of all given coords, expanded in each direction by 1
+fn expanded_bounding_box(coords: &HashSet<Coord3>) -> Cuboid {
+    Cuboid(
+        (coords.iter().map(|c| c.x).min().unwrap() - 1)..=coords.iter().map(|c| c.x).max().unwrap() + 1,
+        (coords.iter().map(|c| c.y).min().unwrap() - 1)..=coords.iter().map(|c| c.y).max().unwrap() + 1,
+        (coords.iter().map(|c| c.z).min().unwrap() - 1)..=coords.iter().map(|c| c.z).max().unwrap() + 1,
+    )
+}
+
+// Parses at least one cube, since there's no bounding box around none
+pub fn parse_cubes(input: &str) -> Result<HashSet<Coord3>, ParseError> {
+    let cubes = input.lines().map(|line: &str| {
+        let (x, rest) = until(input, line, ",")?;
+        let (y, z) = until(input, rest, ",")?;
+        Ok(Coord3::new(number(input, x)?, number(input, y)?, number(input, z)?))
+    }).collect::<Result<HashSet<_>, ParseError>>()?;
+    if cubes.is_empty() {
+        return Err(ParseError::at(input, end_of(input), "a cube like \"1,2,3\""));
+    }
+    Ok(cubes)
+}
+
+pub fn surface_area(cubes: &HashSet<Coord3>) -> usize {
+    cubes.iter().map(|cube| {
+        cube.neighbours().filter(|n| !cubes.contains(n)).count()
+    }).sum::<usize>()
+}
+
+pub fn exterior_surface_area(cubes: &HashSet<Coord3>) -> usize {
+    let bounding_box = expanded_bounding_box(cubes);
+    let min_coord = bounding_box.min_coord();
+    assert!(!cubes.contains(&min_coord), "The min coord of the cubes' bounding box should not contain a cube, by definition");
+
+    let mut queue = VecDeque::from([min_coord]);
+    let mut visited = HashSet::from([min_coord]);
+    let mut exterior_surface_area = 0;
+    while let Some(coord) = queue.pop_front() {
+        for neighbour in coord.neighbours() {
+            if !bounding_box.contains(&neighbour) || visited.contains(&neighbour) {
+                continue;
+            }
+            if cubes.contains(&neighbour) {
+                exterior_surface_area += 1;
+            } else {
+                queue.push_back(neighbour);
+                visited.insert(neighbour);
+            }
+        }
+    }
+    exterior_surface_area
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(surface_area(&parse_cubes(EXAMPLE).unwrap()), 64);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(exterior_surface_area(&parse_cubes(EXAMPLE).unwrap()), 58);
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::VecDeque;
+use rayon::prelude::*;
+use aoc_common::ParseError;
+use aoc_common::parse::{literal, number, until};
+
+pub struct Blueprint {
+    pub id: usize,
+    pub ore_ore: usize,
+    pub clay_ore: usize,
+    pub obsidian_ore: usize,
+    pub obsidian_clay: usize,
+    pub geode_ore: usize,
+    pub geode_obsidian: usize,
+    pub max_ore: usize, // the max ore required to build any of the robots
+}
+
+struct State {
+    ore: usize, clay: usize, obsidian: usize, geode: usize,
+    ore_robots: usize, clay_robots: usize, obsidian_robots: usize, geode_robots: usize,
+    time_remaining: usize,
+}
+
+impl State {
+    fn default(time_remaining: usize) -> State {
+        State {
+            ore: 0, clay: 0, obsidian: 0, geode: 0,
+            ore_robots: 1, clay_robots: 0, obsidian_robots: 0, geode_robots: 0,
+            time_remaining
+        }
+    }
+
+    fn idle_for(&self, mins: usize) -> State {
+        State {
+            ore: self.ore + (self.ore_robots * mins),
+            clay: self.clay + (self.clay_robots * mins),
+            obsidian: self.obsidian + (self.obsidian_robots * mins),
+            geode: self.geode + (self.geode_robots * mins),
+            time_remaining: self.time_remaining - mins,
+            ..*self
+        }
+    }
+
+    fn build_ore_robot(&self, ore_cost: usize) -> State {
+        State {
+            ore: self.ore + self.ore_robots - ore_cost,
+            clay: self.clay + self.clay_robots,
+            obsidian: self.obsidian + self.obsidian_robots,
+            geode: self.geode + self.geode_robots,
+            time_remaining: self.time_remaining - 1,
+            ore_robots: self.ore_robots + 1,
+            ..*self
+        }
+    }
+
+    fn build_clay_robot(&self, ore_cost: usize) -> State {
+        State {
+            ore: self.ore + self.ore_robots - ore_cost,
+            clay: self.clay + self.clay_robots,
+            obsidian: self.obsidian + self.obsidian_robots,
+            geode: self.geode + self.geode_robots,
+            time_remaining: self.time_remaining - 1,
+            clay_robots: self.clay_robots + 1,
+            ..*self
+        }
+    }
+
+    fn build_obsidian_robot(&self, ore_cost: usize, clay_cost: usize) -> State {
+        State {
+            ore: self.ore + self.ore_robots - ore_cost,
+            clay: self.clay + self.clay_robots - clay_cost,
+            obsidian: self.obsidian + self.obsidian_robots,
+            geode: self.geode + self.geode_robots,
+            time_remaining: self.time_remaining - 1,
+            obsidian_robots: self.obsidian_robots + 1,
+            ..*self
+        }
+    }
+
+    fn build_geode_robot(&self, ore_cost: usize, obsidian_cost: usize) -> State {
+        State {
+            ore: self.ore + self.ore_robots - ore_cost,
+            clay: self.clay + self.clay_robots,
+            obsidian: self.obsidian + self.obsidian_robots - obsidian_cost,
+            geode: self.geode + self.geode_robots,
+            time_remaining: self.time_remaining - 1,
+            geode_robots: self.geode_robots + 1,
+            ..*self
+        }
+    }
+
+    // The number of geodes we'll end up with if we build no further robots
+    fn forecast_geodes(&self) -> usize {
+        self.geode + self.time_remaining * self.geode_robots
+    }
+
+    // An upper bound on the number of geodes we could possibly create from this point. Based on the
+    // (almost certainly false) assumption that we can simply do nothing but build geode robots for
+    // the remaining time
+    fn geodes_upper_bound(&self) -> usize {
+        self.forecast_geodes() + (self.time_remaining * (self.time_remaining - 1)) / 2
+    }
+}
+
+impl Blueprint {
+    pub fn maximal_geodes(&self, time_limit: usize) -> usize {
+        let mut queue = VecDeque::new();
+        queue.push_back(State::default(time_limit));
+
+        let mut max_geodes_at_end = 0;
+        while let Some(state) = queue.pop_front() {
+            max_geodes_at_end = max_geodes_at_end.max(state.forecast_geodes());
+            if state.time_remaining == 0 {
+                continue;
+            }
+
+            // If the upper bound on the number of geodes we'd end up with is fewer than the max
+            // we've already seen in another branch, we can throw this branch away.
+            if state.geodes_upper_bound() <= max_geodes_at_end {
+                continue;
+            }
+
+            // Build an ore robot
+            if state.ore_robots > 0 {
+                let ore_required = self.ore_ore.saturating_sub(state.ore);
+                let mins_required = ore_required.div_ceil(state.ore_robots);
+                if mins_required < state.time_remaining {
+                    let ore_deficit_per_min = self.max_ore as isize - state.ore_robots as isize;
+                    let ore_stockpile_required = ore_deficit_per_min * state.time_remaining as isize;
+                    if ore_stockpile_required > state.ore as isize {
+                        let next_state = state.idle_for(mins_required)
+                            .build_ore_robot(self.ore_ore);
+                        queue.push_back(next_state);
+                    }
+                }
+            }
+
+            // Build a clay robot
+            if state.ore_robots > 0 {
+                let ore_required = self.clay_ore.saturating_sub(state.ore);
+                let mins_required = ore_required.div_ceil(state.ore_robots);
+                if mins_required < state.time_remaining {
+                    let clay_deficit_per_min = state.clay_robots as isize - self.obsidian_clay as isize;
+                    let clay_stockpile_required = clay_deficit_per_min * state.time_remaining as isize;
+                    if clay_stockpile_required < state.clay as isize {
+                        let next_state = state.idle_for(mins_required)
+                            .build_clay_robot(self.clay_ore);
+                        queue.push_back(next_state);
+                    }
+                }
+            }
+
+            // Build an obsidian robot
+            if state.ore_robots > 0 && state.clay_robots > 0 {
+                let ore_required = self.obsidian_ore.saturating_sub(state.ore);
+                let ore_mins_required = ore_required.div_ceil(state.ore_robots);
+                let clay_required = self.obsidian_clay.saturating_sub(state.clay);
+                let clay_mins_required = clay_required.div_ceil(state.clay_robots);
+                let mins_required = ore_mins_required.max(clay_mins_required);
+
+                if mins_required < state.time_remaining {
+                    let obsidian_deficit_per_min = state.obsidian_robots as isize - self.geode_obsidian as isize;
+                    let obsidian_stockpile_required = obsidian_deficit_per_min * state.time_remaining as isize;
+                    if obsidian_stockpile_required < state.obsidian as isize {
+                        let next_state = state.idle_for(mins_required)
+                            .build_obsidian_robot(self.obsidian_ore, self.obsidian_clay);
+                        queue.push_back(next_state);
+                    }
+                }
+            }
+
+            // Build a geode robot
+            if state.ore_robots > 0 && state.obsidian_robots > 0 {
+                let ore_required = self.geode_ore.saturating_sub(state.ore);
+                let ore_mins_required = ore_required.div_ceil(state.ore_robots);
+                let obsidian_required = self.geode_obsidian.saturating_sub(state.obsidian);
+                let obsidian_mins_required = obsidian_required.div_ceil(state.obsidian_robots);
+                let mins_required = ore_mins_required.max(obsidian_mins_required);
+
+                if mins_required < state.time_remaining {
+                    let next_state = state.idle_for(mins_required)
+                        .build_geode_robot(self.geode_ore, self.geode_obsidian);
+                    queue.push_back(next_state);
+                }
+            }
+        }
+        max_geodes_at_end
+    }
+
+    pub fn quality_level(&self, time_limit: usize) -> usize {
+        self.maximal_geodes(time_limit) * self.id
+    }
+}
+
+pub fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    input.lines().map(|line| {
+        let (id, rest) = until(input, literal(input, line, "Blueprint ")?, ": ")?;
+        let (ore_ore, rest) = until(input, literal(input, rest, "Each ore robot costs ")?, " ore. ")?;
+        let (clay_ore, rest) = until(input, literal(input, rest, "Each clay robot costs ")?, " ore. ")?;
+        let (obsidian_ore, rest) = until(input, literal(input, rest, "Each obsidian robot costs ")?, " ore and ")?;
+        let (obsidian_clay, rest) = until(input, rest, " clay. ")?;
+        let (geode_ore, rest) = until(input, literal(input, rest, "Each geode robot costs ")?, " ore and ")?;
+        let (geode_obsidian, _) = until(input, rest, " obsidian.")?;
+        let (id, ore_ore, clay_ore, obsidian_ore) =
+            (number(input, id)?, number(input, ore_ore)?, number(input, clay_ore)?, number(input, obsidian_ore)?);
+        let (obsidian_clay, geode_ore, geode_obsidian) =
+            (number(input, obsidian_clay)?, number(input, geode_ore)?, number(input, geode_obsidian)?);
+        let max_ore = *[ore_ore, clay_ore, obsidian_ore, geode_ore].iter().max().unwrap();
+        Ok(Blueprint { id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian, max_ore })
+    }).collect()
+}
+
+pub fn sum_quality_levels(blueprints: &[Blueprint], time_limit: usize) -> usize {
+    blueprints.par_iter().map(|b| b.quality_level(time_limit)).sum()
+}
+
+pub fn product_of_first_three_maximal_geodes(blueprints: &[Blueprint], time_limit: usize) -> usize {
+    blueprints.par_iter().take(3).map(|b| b.maximal_geodes(time_limit)).product()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    /*
+Blueprint 1:
+  Each ore robot costs 4 ore.
+  Each clay robot costs 2 ore.
+  Each obsidian robot costs 3 ore and 14 clay.
+  Each geode robot costs 2 ore and 7 obsidian.
+     */
+    #[test]
+    fn example_1_quality() {
+        let b = Blueprint {
+            id: 1,
+            ore_ore: 4,
+            clay_ore: 2,
+            obsidian_ore: 3, obsidian_clay: 14,
+            geode_ore: 2, geode_obsidian: 7,
+            max_ore: 4,
+        };
+        let q = b.quality_level(24);
+        assert_eq!(q, 9);
+    }
+
+    /*
+Blueprint 2:
+  Each ore robot costs 2 ore.
+  Each clay robot costs 3 ore.
+  Each obsidian robot costs 3 ore and 8 clay.
+  Each geode robot costs 3 ore and 12 obsidian
+     */
+    #[test]
+    fn example_2_quality() {
+        let b = Blueprint {
+            id: 2,
+            ore_ore: 2,
+            clay_ore: 3,
+            obsidian_ore: 3, obsidian_clay: 8,
+            geode_ore: 3, geode_obsidian: 12,
+            max_ore: 3,
+        };
+        let q = b.quality_level(24);
+        assert_eq!(q, 24);
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(sum_quality_levels(&parse_blueprints(EXAMPLE).unwrap(), 24), 33);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(product_of_first_three_maximal_geodes(&parse_blueprints(EXAMPLE).unwrap(), 32), 56 * 62);
+    }
+}
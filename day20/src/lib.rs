@@ -0,0 +1,63 @@
+use aoc_common::ParseError;
+use aoc_common::parse::{end_of, number};
+
+pub fn mix(tracked_numbers: &mut Vec<(isize, usize)>) {
+    let mut i = 0;
+    for to_move in 0..tracked_numbers.len() {
+        while tracked_numbers[i].1 != to_move {
+            i = (i + 1) % tracked_numbers.len();
+        }
+        let tmp = tracked_numbers.remove(i);
+        let new_pos = (i as isize + tmp.0).rem_euclid(tracked_numbers.len() as isize) as usize;
+        tracked_numbers.insert(new_pos, tmp);
+    }
+}
+
+pub fn sum_coordinates(tracked_numbers: &[(isize, usize)]) -> isize {
+    let zero_pos = tracked_numbers.iter().position(|(n,_)| n == &0).unwrap();
+    let one = (zero_pos + 1000) % tracked_numbers.len();
+    let two = (zero_pos + 2000) % tracked_numbers.len();
+    let three = (zero_pos + 3000) % tracked_numbers.len();
+    tracked_numbers[one].0 + tracked_numbers[two].0 + tracked_numbers[three].0
+}
+
+// Parses each number along with its original position. Coordinates are found from the 0, and a
+// number can only be moved if there's another to move past.
+pub fn parse_tracked_numbers(input: &str) -> Result<Vec<(isize, usize)>, ParseError> {
+    let tracked_numbers = input.lines()
+        .enumerate()
+        .map(|(i, line)| Ok((number(input, line)?, i)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    if tracked_numbers.len() < 2 {
+        return Err(ParseError::at(input, end_of(input), "at least two numbers"));
+    }
+    if !tracked_numbers.iter().any(|(n, _)| *n == 0) {
+        return Err(ParseError::at(input, end_of(input), "a 0"));
+    }
+    Ok(tracked_numbers)
+}
+
+pub fn decrypt(tracked_numbers: &[(isize, usize)], key: isize, rounds: usize) -> isize {
+    let mut nums = tracked_numbers.iter().map(|(n, i)| (n * key, *i)).collect::<Vec<_>>();
+    for _ in 0..rounds {
+        mix(&mut nums);
+    }
+    sum_coordinates(&nums)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(decrypt(&parse_tracked_numbers(EXAMPLE).unwrap(), 1, 1), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(decrypt(&parse_tracked_numbers(EXAMPLE).unwrap(), 811589153, 10), 1623178306);
+    }
+}
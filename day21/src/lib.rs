@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::ParseError;
+use aoc_common::parse::{end_of, until};
+
+pub enum Node {
+    Number(isize),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+impl Node {
+    fn has_dependent(&self, name: &String) -> bool {
+        match self {
+            Node::Number(_) => false,
+            Node::Add(l, r) | Node::Sub(l, r) | Node::Mul(l, r) | Node::Div(l, r) => l == name || r == name,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<HashMap<String, Node>, ParseError> {
+    use Node::*;
+    let mut lookup = HashMap::new();
+    let mut operand_names = vec![];
+    for line in input.lines() {
+        let (name, expression) = until(input, line, ": ")?;
+        if let Ok(number) = expression.parse() {
+            lookup.insert(name.to_string(), Number(number));
+        } else {
+            let (lhs, rest) = until(input, expression, " ")?;
+            let (op, rhs) = until(input, rest, " ")?;
+            operand_names.extend([lhs, rhs]);
+            let (lhs, rhs) = (lhs.to_string(), rhs.to_string());
+            let node = match op {
+                "+" => Add(lhs, rhs),
+                "-" => Sub(lhs, rhs),
+                "*" => Mul(lhs, rhs),
+                "/" => Div(lhs, rhs),
+                _ => return Err(ParseError::at(input, op, "an operation (+, -, * or /)")),
+            };
+            lookup.insert(name.to_string(), node);
+        }
+    }
+    if let Some(unknown) = operand_names.iter().find(|&&n| !lookup.contains_key(n)) {
+        return Err(ParseError::at(input, unknown, "the name of a monkey"));
+    }
+    for name in ["root", "humn"] {
+        if !lookup.contains_key(name) {
+            return Err(ParseError::at(input, end_of(input), format!("a monkey named {}", name)));
+        }
+    }
+    Ok(lookup)
+}
+
+pub fn calculate(name: &String, lookup: &HashMap<String, Node>) -> isize {
+    use Node::*;
+    match lookup.get(name) {
+        Some(Number(n)) => *n,
+        Some(Add(l, r)) => calculate(l, lookup) + calculate(r, lookup),
+        Some(Sub(l, r)) => calculate(l, lookup) - calculate(r, lookup),
+        Some(Mul(l, r)) => calculate(l, lookup) * calculate(r, lookup),
+        Some(Div(l, r)) => calculate(l, lookup) / calculate(r, lookup),
+        _ => unreachable!(),
+    }
+}
+
+pub fn find_humn_dependents(lookup: &HashMap<String, Node>) -> HashSet<String> {
+    let mut dependents = HashSet::new();
+    let mut cur = "humn".to_string();
+    while cur != "root" {
+        dependents.insert(cur.clone());
+        let mut nexts = lookup.iter()
+            .filter(|(_, node)| node.has_dependent(&cur))
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        if nexts.len() != 1 {
+            panic!("Expected exactly 1 dependent, found {}", nexts.len());
+        }
+        cur = nexts.pop().unwrap().clone();
+    }
+    dependents
+}
+
+pub fn invert_root(lookup: &HashMap<String, Node>, humn_dependents: &HashSet<String>) -> isize {
+    use Node::*;
+    let (l, r) = match &lookup["root"] {
+        Add(l, r) | Sub(l, r) | Mul(l, r) | Div(l, r) => (l, r),
+        Number(_) => panic!("Root is a number"),
+    };
+    match (humn_dependents.contains(l), humn_dependents.contains(r)) {
+        (true, false) => {
+            invert_to_humn(calculate(r, lookup), l, lookup, humn_dependents)
+        },
+        (false, true) => {
+            invert_to_humn(calculate(l, lookup), r, lookup, humn_dependents)
+        },
+        (true, true) => panic!("Both operands of root depend on humn"),
+        (false, false) => panic!("Neither operand of root depends on humn"),
+    }
+}
+
+fn invert_to_humn(result: isize, name: &String, lookup: &HashMap<String, Node>, humn_dependents: &HashSet<String>) -> isize {
+    use Node::*;
+    if name == &"humn".to_string() {
+        return result;
+    }
+    match &lookup[name] {
+        Number(n) => *n,
+        Add(l, r) => {
+            match (humn_dependents.contains(l), humn_dependents.contains(r)) {
+                (true, false) => invert_to_humn(result - calculate(r, lookup), l, lookup, humn_dependents),
+                (false, true) => invert_to_humn(result - calculate(l, lookup), r, lookup, humn_dependents),
+                (true, true) => panic!("Both operands depend on humn"),
+                (false, false) => panic!("Neither operand depends on humn"),
+            }
+        }
+        Sub(l, r) => {
+            match (humn_dependents.contains(l), humn_dependents.contains(r)) {
+                (true, false) => invert_to_humn(result + calculate(r, lookup), l, lookup, humn_dependents),
+                (false, true) => invert_to_humn(calculate(l, lookup) - result, r, lookup, humn_dependents),
+                (true, true) => panic!("Both operands depend on humn"),
+                (false, false) => panic!("Neither operand depends on humn"),
+            }
+        }
+        Div(l, r) => {
+            match (humn_dependents.contains(l), humn_dependents.contains(r)) {
+                (true, false) => invert_to_humn(result * calculate(r, lookup), l, lookup, humn_dependents),
+                (false, true) => invert_to_humn(calculate(l, lookup) / result, r, lookup, humn_dependents),
+                (true, true) => panic!("Both operands depend on humn"),
+                (false, false) => panic!("Neither operand depends on humn"),
+            }
+        }
+        Mul(l, r) => {
+            match (humn_dependents.contains(l), humn_dependents.contains(r)) {
+                (true, false) => invert_to_humn(result / calculate(r, lookup), l, lookup, humn_dependents),
+                (false, true) => invert_to_humn(result / calculate(l, lookup), r, lookup, humn_dependents),
+                (true, true) => panic!("Both operands depend on humn"),
+                (false, false) => panic!("Neither operand depends on humn"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(calculate(&"root".to_string(), &parse(EXAMPLE).unwrap()), 152);
+    }
+
+    #[test]
+    fn example_part2() {
+        let lookup = parse(EXAMPLE).unwrap();
+        let dependents = find_humn_dependents(&lookup);
+        assert_eq!(invert_root(&lookup, &dependents), 301);
+    }
+}
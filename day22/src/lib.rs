@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::iter::repeat_n;
+use aoc_common::{Coord, Direction, ParseError, Rotation};
+use aoc_common::parse::{end_of, number};
+
+pub type WrapMap = HashMap<Coord, MapCell>;
+
+#[derive(Clone)]
+pub struct MapCell {
+    north: Option<(Coord, Rotation)>,
+    east: Option<(Coord, Rotation)>,
+    south: Option<(Coord, Rotation)>,
+    west: Option<(Coord, Rotation)>,
+}
+
+impl MapCell {
+    fn step(&self, direction: &Direction) -> &Option<(Coord, Rotation)> {
+        use Direction::*;
+        match direction {
+            North => &self.north,
+            East => &self.east,
+            South => &self.south,
+            West => &self.west,
+        }
+    }
+
+    fn update_dir(&self, direction: &Direction, next: Option<(Coord, Rotation)>) -> MapCell {
+        use Direction::*;
+        match direction {
+            North => MapCell{ north: next, ..self.clone() },
+            East => MapCell{ east: next, ..self.clone() },
+            South => MapCell{ south: next, ..self.clone() },
+            West => MapCell{ west: next, ..self.clone() },
+        }
+    }
+}
+
+fn direction_password_score(direction: &Direction) -> usize {
+    use Direction::*;
+    match direction {
+        East => 0,
+        South => 1,
+        West => 2,
+        North => 3,
+    }
+}
+
+#[derive(Clone)]
+pub struct Agent {
+    position: Coord,
+    direction: Direction,
+}
+
+impl Agent {
+    pub fn advance(&mut self, steps: usize, map: &WrapMap) {
+        for _ in 0..steps {
+            let cur_cell = map.get(&self.position).unwrap();
+            let next_pos = cur_cell.step(&self.direction);
+            if let Some((pos, rot)) = next_pos {
+                self.position = *pos;
+                self.turn(rot);
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn turn(&mut self, rotation: &Rotation) {
+        self.direction = self.direction.turn(rotation);
+    }
+
+    pub fn password_score(&self) -> usize {
+        1000 * (self.position.y as usize + 1) +
+            4 * (self.position.x as usize + 1) +
+            direction_password_score(&self.direction)
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Advance(usize),
+    Turn(Rotation),
+}
+
+// Parses the path, line, a slice of input
+pub fn parse_instructions(input: &str, line: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut start = 0;
+    let mut instrs = vec![];
+    for (i, c) in line.bytes().enumerate() {
+        let rotation = match c {
+            b'L' => Rotation::Left,
+            b'R' => Rotation::Right,
+            _ => continue,
+        };
+        if i > start {
+            instrs.push(Instruction::Advance(number(input, &line[start..i])?));
+        }
+        instrs.push(Instruction::Turn(rotation));
+        start = i + 1;
+    }
+    if line.len() > start {
+        instrs.push(Instruction::Advance(number(input, &line[start..])?));
+    }
+    Ok(instrs)
+}
+
+pub fn parse_input(input: &str) -> Result<(Agent, WrapMap, Vec<Instruction>), ParseError> {
+    let mut map = HashMap::new();
+    let mut agent = Agent { position: Coord::default(), direction: Direction::East };
+
+    let mut map_chars = HashMap::new();
+    let mut width = 0;
+    let mut height = 0;
+    for (y, line) in input.lines().take_while(|l| !l.is_empty()).enumerate() {
+        for (x, c) in line.char_indices() {
+            if !matches!(c, ' ' | '.' | '#') {
+                return Err(ParseError::at(input, &line[x..x + c.len_utf8()], "an open tile (.), a wall (#) or a space"));
+            }
+            let coord = Coord::new(x as isize, y as isize);
+            map_chars.insert(coord, c as u8);
+            width = width.max(coord.x + 1);
+            height = height.max(coord.y + 1);
+        }
+    }
+
+    let mut has_found_agent_start = false;
+    for y in 0..height {
+        for x in 0..width {
+            let coord = Coord::new(x, y);
+            if let Some(b'.') = map_chars.get(&coord) {
+                if y == 0 && !has_found_agent_start {
+                    agent.position = coord;
+                    has_found_agent_start = true;
+                }
+                let [north, east, south, west] = Direction::ALL
+                    .map(|dir| wrapped_neighbour(&map_chars, coord, dir, width, height));
+                let cell = MapCell { north, east, south, west };
+                map.insert(coord, cell);
+            }
+        }
+    }
+    // The agent starts on the leftmost open tile of the top row
+    if !has_found_agent_start {
+        let top_row = input.lines().next().unwrap_or("");
+        return Err(ParseError::at(input, end_of(top_row), "an open tile (.) in the top row"));
+    }
+    let instructions = parse_instructions(input, input.lines().last().unwrap_or(""))?;
+    Ok((agent, map, instructions))
+}
+
+// Find the next open tile in the given direction, wrapping around the edges of the map, or None if
+// a wall is hit first
+fn wrapped_neighbour(
+    map_chars: &HashMap<Coord, u8>,
+    coord: Coord,
+    direction: Direction,
+    width: isize,
+    height: isize,
+) -> Option<(Coord, Rotation)> {
+    let mut next = coord;
+    loop {
+        next += direction.delta();
+        next = Coord::new(next.x.rem_euclid(width), next.y.rem_euclid(height));
+        match map_chars.get(&next) {
+            Some(b'.') => return Some((next, Rotation::Nothing)),
+            Some(b'#') => return None,
+            _ => {}
+        }
+    }
+}
+
+/*
+  1122
+  33
+4455
+66
+
+1N -> 6R  2N -> 6N  3N -> 1N  4N -> 3R  5N -> 3N  6N -> 4N
+1E -> 2N  2E -> 5H  3E -> 2L  4E -> 5N  5E -> 2F  6E -> 5L
+1S -> 3N  2S -> 3R  3S -> 5N  4S -> 6N  5S -> 6R  6S -> 2N
+1W -> 4H  2W -> 1N  3W -> 4L  4W -> 1H  5W -> 4N  6W -> 1L
+ */
+pub fn convert_to_cube(map: &mut WrapMap) {
+    use Direction::*;
+    use Rotation::*;
+
+    // Top of 1 <-> left of 6
+
+    // Top of 1 goes to left of 6, rotating right (l->r : t->b) (50,0)..(100,0) -> (0,150)..(0,200)
+    update_line(
+        map,
+        &mut (50..100),
+        &mut repeat_n(0, 50),
+        &mut repeat_n(0, 50),
+        &mut (150..200),
+        North,
+        Right,
+    );
+    // Left of 6 goes to top of 1 left turn
+    update_line(
+        map,
+        &mut repeat_n(0, 50),
+        &mut (150..200),
+        &mut (50..100),
+        &mut repeat_n(0, 50),
+        West,
+        Left,
+    );
+
+
+    // Top of 2 <-> bottom of 6
+
+    // Top of 2 goes to bottom of 6, no rotation (100,0)..(150,0) -> (0,199)..(50,199)
+    update_line(
+        map,
+        &mut (100..150),
+        &mut repeat_n(0, 50),
+        &mut (0..50),
+        &mut repeat_n(199, 50),
+        North,
+        Nothing,
+    );
+    // Bottom of 6 goes to top of 2, no rotation
+    update_line(
+        map,
+        &mut (0..50),
+        &mut repeat_n(199, 50),
+        &mut (100..150),
+        &mut repeat_n(0, 50),
+        South,
+        Nothing,
+    );
+
+
+    // Right of 2 <-> right of 5
+
+    // Right of 2 goes to right of 5, 180 rotation (t->b : b->t)  (149,0)..(149,50) -> (99,150)..(99,100)
+    update_line(
+        map,
+        &mut repeat_n(149, 50),
+        &mut (0..50),
+        &mut repeat_n(99, 50),
+        &mut (100..150).rev(),
+        East,
+        Half,
+    );
+    // Right of 5 goes to right of 2, 180 turn (t->b : b->t) (99,100)..(99,150) to (149,49)..=(149,0)
+    update_line(
+        map,
+        &mut repeat_n(99, 50),
+        &mut (100..150),
+        &mut repeat_n(149, 50),
+        &mut (0..50).rev(),
+        East,
+        Half,
+    );
+
+
+    // Bottom of 2 <-> right of 3
+
+    // Bottom of 2 goes to right of 3, rotating right (l->r : t->b)  (100,49)..(150,49) -> (99,50)..(99,100)
+    update_line(
+        map,
+        &mut (100..150),
+        &mut repeat_n(49, 50),
+        &mut repeat_n(99, 50),
+        &mut (50..100),
+        South,
+        Right,
+    );
+    // Right of 3 goes to bottom of 2, rotating left (t->b : l->r)  (99,50)..(99,100) -> (100,49)..(150,49)
+    update_line(
+        map,
+        &mut repeat_n(99, 50),
+        &mut (50..100),
+        &mut (100..150),
+        &mut repeat_n(49, 50),
+        East,
+        Left,
+    );
+
+
+    // Bottom of 5 <-> right of 6
+
+    // Bottom of 5 goes to right of 6, right turn (l->r : t->b)  (50,149)..(100,149) to (49,150)..(49,200)
+    update_line(
+        map,
+        &mut (50..100),
+        &mut repeat_n(149, 50),
+        &mut repeat_n(49, 50),
+        &mut (150..200),
+        South,
+        Right,
+    );
+    // Right of 6 goes to bottom of 5, left turn  (49,150)..(49,200) to (50,149)..(100,149)
+    update_line(
+        map,
+        &mut repeat_n(49, 50),
+        &mut (150..200),
+        &mut (50..100),
+        &mut repeat_n(149, 50),
+        East,
+        Left,
+    );
+
+
+    // Left of 4 <-> left of 1
+
+    // Left of 4 goes to left of 1, 180 turn, (0,100)..(0,150) to (50,50)..(50,0)
+    update_line(
+        map,
+        &mut repeat_n(0, 50),
+        &mut (100..150),
+        &mut repeat_n(50, 50),
+        &mut (0..50).rev(),
+        West,
+        Half,
+    );
+    // Left of 1 goes to left of 4
+    update_line(
+        map,
+        &mut repeat_n(50, 50),
+        &mut (0..50),
+        &mut repeat_n(0, 50),
+        &mut (100..150).rev(),
+        West,
+        Half,
+    );
+
+
+    // Top of 4 <-> left of 3
+
+    // Top of 4 goes to left of 3, right turn, (0,100)..(50,100) to (50,50)..(50,100)
+    update_line(
+        map,
+        &mut (0..50),
+        &mut repeat_n(100, 50),
+        &mut repeat_n(50, 50),
+        &mut (50..100),
+        North,
+        Right,
+    );
+    // Left of 3 goes to top of 4
+    update_line(
+        map,
+        &mut repeat_n(50, 50),
+        &mut (50..100),
+        &mut (0..50),
+        &mut repeat_n(100, 50),
+        West,
+        Left,
+    );
+}
+
+fn update_line(
+    map: &mut WrapMap,
+    from_xs: &mut impl Iterator<Item = isize>,
+    from_ys: &mut impl Iterator<Item = isize>,
+    to_xs: &mut impl Iterator<Item = isize>,
+    to_ys: &mut impl Iterator<Item = isize>,
+    from_direction: Direction,
+    rotation: Rotation,
+) {
+    for _ in 0..50 {
+        let from_x = from_xs.next().unwrap(); let from_y = from_ys.next().unwrap();
+        let to_x = to_xs.next().unwrap(); let to_y = to_ys.next().unwrap();
+
+        let from_coord = Coord::new(from_x, from_y);
+        if let Some(old) = map.get(&from_coord) {
+            let to_coord = Coord::new(to_x, to_y);
+            let next = map.contains_key(&to_coord).then_some((to_coord, rotation));
+            map.insert(from_coord, old.update_dir(&from_direction, next));
+        }
+    }
+}
+
+pub fn follow_instructions(agent: &Agent, map: &WrapMap, instructions: &[Instruction]) -> usize {
+    let mut agent = agent.clone();
+    for instr in instructions {
+        match instr {
+            Instruction::Advance(n) => agent.advance(*n, map),
+            Instruction::Turn(rot) => agent.turn(rot),
+        }
+    }
+    agent.password_score()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let (agent, map, instructions) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(follow_instructions(&agent, &map, &instructions), 6032);
+    }
+}
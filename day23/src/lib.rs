@@ -0,0 +1,141 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use aoc_common::{Coord, ParseError};
+use aoc_common::parse::end_of;
+
+type Deltas = (Coord, Coord, Coord);
+
+const DIRECTIONS: [Deltas; 4] = [
+    (Coord::new(-1, -1), Coord::new(0, -1), Coord::new(1, -1)), // N
+    (Coord::new(-1,  1), Coord::new(0,  1), Coord::new(1,  1)), // S
+    (Coord::new(-1, -1), Coord::new(-1, 0), Coord::new(-1, 1)), // W
+    (Coord::new( 1, -1), Coord::new(1,  0), Coord::new(1,  1)), // E
+];
+
+// Parses at least one elf, since there's no rectangle around none
+pub fn parse_elves(input: &str) -> Result<HashSet<Coord>, ParseError> {
+    let mut elves = HashSet::new();
+    for (y, row) in input.lines().enumerate() {
+        for (x, c) in row.char_indices() {
+            match c {
+                '#' => { elves.insert(Coord::new(x as isize, y as isize)); },
+                '.' => {},
+                _ => return Err(ParseError::at(input, &row[x..x + c.len_utf8()], "an elf (#) or ground (.)")),
+            }
+        }
+    }
+    if elves.is_empty() {
+        return Err(ParseError::at(input, end_of(input), "an elf (#)"));
+    }
+    Ok(elves)
+}
+
+// Runs a single round of the elves spreading out, returning whether any elf proposed a move
+pub fn run_round(elves: &mut HashSet<Coord>, round: usize) -> bool {
+    // Determine the order of directions to check this round
+    let round_dirs = DIRECTIONS.iter().cycle().skip((round - 1) % DIRECTIONS.len()).take(4);
+
+    // Find where each elf proposes to move (if anywhere)
+    let mut proposals: HashMap<Coord, Vec<Coord>> = HashMap::new();
+    for elf in elves.iter() {
+        // Check if this elf has any neighbours - if so, it should consider moving
+        let should_move = elf.all_neighbours().any(|n| elves.contains(&n));
+        if !should_move {
+            continue;
+        }
+
+        // Find the first direction (if any) this elf would like to propose to move
+        for (delta_a, delta_b, delta_c) in round_dirs.clone() {
+            let a = *elf + *delta_a;
+            let b = *elf + *delta_b;
+            let c = *elf + *delta_c;
+            if !elves.contains(&a) && !elves.contains(&b) && !elves.contains(&c) {
+                proposals.entry(b).or_default().push(*elf);
+                break;
+            }
+        }
+    }
+
+    // If no elf proposed a move, we're done
+    if proposals.is_empty() {
+        return false;
+    }
+
+    // Find only the places proposed by a single elf and move them
+    for (proposed_coord, elf_coords) in proposals.into_iter().filter(|(_, elf_coords)| elf_coords.len() == 1) {
+        elves.remove(&elf_coords[0]);
+        elves.insert(proposed_coord);
+    }
+
+    true
+}
+
+pub fn empty_spaces_after_rounds(elves: &HashSet<Coord>, rounds: usize) -> usize {
+    let mut elves = elves.clone();
+    for round in 1..=rounds {
+        run_round(&mut elves, round);
+    }
+    num_free_spaces(&elves)
+}
+
+pub fn first_round_without_moves(elves: &HashSet<Coord>) -> usize {
+    let mut elves = elves.clone();
+    let mut round = 1;
+    while run_round(&mut elves, round) {
+        round += 1;
+
+        // draw(&elves);
+        // println!();
+    }
+    round
+}
+
+fn bounds(elves: &HashSet<Coord>) -> (RangeInclusive<isize>, RangeInclusive<isize>) {
+    let mut min_x = 100_000; let mut max_x = -100_000;
+    let mut min_y = 100_000; let mut max_y = -100_000;
+    for elf in elves {
+        min_x = elf.x.min(min_x);
+        min_y = elf.y.min(min_y);
+        max_x = elf.x.max(max_x);
+        max_y = elf.y.max(max_y);
+    }
+    (min_x..=max_x, min_y..=max_y)
+}
+
+pub fn num_free_spaces(elves: &HashSet<Coord>) -> usize {
+    let (xrange, yrange) = bounds(elves);
+    let width = (xrange.end() - xrange.start() + 1) as usize;
+    let height = (yrange.end() - yrange.start() + 1) as usize;
+    width * height - elves.len()
+}
+
+pub fn draw(elves: &HashSet<Coord>) {
+    let (xrange, yrange) = bounds(elves);
+    for y in yrange {
+        for x in xrange.clone() {
+            if elves.contains(&Coord::new(x, y)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(empty_spaces_after_rounds(&parse_elves(EXAMPLE).unwrap(), 10), 110);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(first_round_without_moves(&parse_elves(EXAMPLE).unwrap()), 20);
+    }
+}
@@ -0,0 +1,29 @@
+// Two approaches to the same puzzle: the original, which precalculates a snapshot of every blizzard
+// state, and a faster version that checks for blizzards by rewinding them to their starting positions
+pub mod snapshots;
+pub mod rewind;
+
+use aoc_common::{Grid, ParseError};
+use aoc_common::parse::end_of;
+
+// Checks that a parsed map has an inside for the blizzards to cross, with the entrance in the top
+// row just right of the corner and the exit in the bottom row just left of it, as both approaches
+// assume
+fn check_valley<T>(input: &str, cells: &Grid<T>, is_gap: impl Fn(&T) -> bool) -> Result<(), ParseError> {
+    let (width, height) = (cells.width(), cells.height());
+    if height < 3 {
+        return Err(ParseError::at(input, end_of(input), "at least 3 rows"));
+    }
+    if width < 3 {
+        let first_line = input.lines().next().unwrap_or("");
+        return Err(ParseError::at(input, first_line, "a row at least 3 wide"));
+    }
+    for (x, y, gap) in [(1, 0, "the entrance"), (width - 2, height - 1, "the exit")] {
+        if !is_gap(&cells[(x, y)]) {
+            // Every cell has been parsed from an ASCII character, so x is also a byte offset
+            let line = input.lines().nth(y).unwrap_or("");
+            return Err(ParseError::at(input, &line[x..x + 1], format!("\".\" for {}", gap)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,279 @@
+use std::collections::{HashSet, VecDeque};
+use aoc_common::{Grid, ParseError};
+
+#[repr(u8)]
+#[derive(Eq, PartialEq)]
+enum Cell {
+    Open,
+    Wall,
+    North,
+    East,
+    South,
+    West,
+}
+
+pub struct Map {
+    pub width: usize,
+    pub height: usize,
+    cells: Grid<Cell>,
+}
+
+impl Map {
+    pub fn parse(input: &str) -> Result<Map, ParseError> {
+        use Cell::*;
+        let cells = Grid::try_parse(input, |c| {
+            match c {
+                '.' => Ok(Open),
+                '#' => Ok(Wall),
+                '^' => Ok(North),
+                '>' => Ok(East),
+                'v' => Ok(South),
+                '<' => Ok(West),
+                _ => Err("one of . # ^ > v <")
+            }
+        })?;
+        crate::check_valley(input, &cells, |cell| *cell == Open)?;
+        Ok(Map { height: cells.height(), width: cells.width(), cells })
+    }
+
+    pub fn is_open(&self, x: usize, y: usize, t: usize) -> bool {
+        use Cell::*;
+        
+        // Walls
+        if self.cells[(x, y)] == Wall {
+            return false;
+        }
+
+        // South: Look up for blizzards travelling south
+        let y_at_zero = (y as isize - 1 - t as isize).rem_euclid(self.height as isize - 2) as usize + 1;
+        if self.cells[(x, y_at_zero)] == South {
+            return false;
+        }
+
+        // North
+        let y_at_zero = (y as isize - 1 + t as isize).rem_euclid(self.height as isize - 2) as usize + 1;
+        if self.cells[(x, y_at_zero)] == North {
+            return false;
+        }
+
+        // East
+        let x_at_zero = (x as isize - 1 - t as isize).rem_euclid(self.width as isize - 2) as usize + 1;
+        if self.cells[(x_at_zero, y)] == East {
+            return false;
+        }
+
+        // West
+        let x_at_zero = (x as isize - 1 + t as isize).rem_euclid(self.width as isize - 2) as usize + 1;
+        if self.cells[(x_at_zero, y)] == West {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn open_neighbours(&self, x: usize, y: usize, t: usize) -> Vec<(usize, usize)> {
+        let mut result = vec![];
+
+        // Up
+        if y > 0 && self.is_open(x, y - 1, t) {
+            result.push((x, y - 1));
+        }
+
+        // Down
+        if y < self.height - 1 && self.is_open(x, y + 1, t) {
+            result.push((x, y + 1));
+        }
+
+        // Left
+        if x > 0 && self.is_open(x - 1, y, t) {
+            result.push((x - 1, y));
+        }
+
+        // Right
+        if x < self.width - 1 && self.is_open(x + 1, y, t) {
+            result.push((x + 1, y));
+        }
+
+        // Wait
+        if self.is_open(x, y, t) {
+            result.push((x, y));
+        }
+
+        result
+    }
+}
+
+pub fn find_time_at_reaching(start: (usize, usize), end: (usize, usize), start_time: usize, map: &Map) -> usize {
+    let mut queue = VecDeque::new();
+    queue.push_back((start.0, start.1, start_time));
+    let mut visited = HashSet::new();
+    visited.insert((start.0, start.1, start_time));
+
+    while let Some((x, y, t)) = queue.pop_front() {
+        for (nx, ny) in map.open_neighbours(x, y, t + 1) {
+            if (nx, ny) == end {
+                return t + 1;
+            }
+            if visited.contains(&(nx, ny, t + 1)) {
+                continue;
+            }
+            queue.push_back((nx, ny, t + 1));
+            visited.insert((nx, ny, t + 1));
+        }
+    }
+
+    panic!("Could not find route between {:?} @ {} and {:?}", start, start_time, end)
+}
+
+pub fn time_to_exit(map: &Map) -> usize {
+    find_time_at_reaching((1, 0), (map.width - 2, map.height - 2), 0, map) + 1
+}
+
+pub fn time_to_exit_return_and_exit_again(map: &Map) -> usize {
+    let first_exit = time_to_exit(map);
+    let after_returning = find_time_at_reaching((map.width - 2, map.height - 1), (1, 1), first_exit, map) + 1;
+    find_time_at_reaching((1, 0), (map.width - 2, map.height - 2), after_returning, map) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_time_at_reaching, time_to_exit, time_to_exit_return_and_exit_again, Map};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn east_blizzard() {
+        let map = Map::parse("#.###\n#>..#\n#...#\n#...#\n###.#").unwrap();
+
+        assert_not_open(&map, &[(1, 1)], 0);
+        assert_not_open(&map, &[(2, 1)], 1);
+        assert_not_open(&map, &[(3, 1)], 2);
+        assert_not_open(&map, &[(1, 1)], 3);
+    }
+
+    #[test]
+    fn west_blizzard() {
+        let map = Map::parse("#.###\n#<..#\n#...#\n#...#\n###.#").unwrap();
+
+        assert_not_open(&map, &[(1, 1)], 0);
+        assert_not_open(&map, &[(3, 1)], 1);
+        assert_not_open(&map, &[(2, 1)], 2);
+        assert_not_open(&map, &[(1, 1)], 3);
+    }
+
+    #[test]
+    fn north_blizzard() {
+        let map = Map::parse("#.###\n#^..#\n#...#\n#...#\n###.#").unwrap();
+
+        assert_not_open(&map, &[(1, 1)], 0);
+        assert_not_open(&map, &[(1, 3)], 1);
+        assert_not_open(&map, &[(1, 2)], 2);
+        assert_not_open(&map, &[(1, 1)], 3);
+    }
+
+
+    #[test]
+    fn south_blizzard() {
+        let map = Map::parse("#.###\n#v..#\n#...#\n#...#\n###.#").unwrap();
+
+        assert_not_open(&map, &[(1, 1)], 0);
+        assert_not_open(&map, &[(1, 2)], 1);
+        assert_not_open(&map, &[(1, 3)], 2);
+        assert_not_open(&map, &[(1, 1)], 3);
+    }
+
+    #[test]
+    fn converging_blizzards() {
+        let map = Map::parse("#.###\n#.v.#\n#>.<#\n#.^.#\n###.#").unwrap();
+
+        assert_not_open(&map, &[(2, 1), (1, 2), (3, 2), (2, 3)], 0);
+        assert_not_open(&map, &[(2, 2)], 1);
+        assert_not_open(&map, &[(2, 1), (1, 2), (3, 2), (2, 3)], 2);
+        assert_not_open(&map, &[(2, 1), (1, 2), (3, 2), (2, 3)], 3);
+        assert_not_open(&map, &[(2, 2)], 4);
+        assert_not_open(&map, &[(2, 1), (1, 2), (3, 2), (2, 3)], 5);
+        assert_not_open(&map, &[(2, 1), (1, 2), (3, 2), (2, 3)], 6);
+        assert_not_open(&map, &[(2, 2)], 7);
+        assert_not_open(&map, &[(2, 1), (1, 2), (3, 2), (2, 3)], 8);
+    }
+
+    #[test]
+    fn neighbours_corners() {
+        let map = Map::parse("#.###\n#...#\n#...#\n#...#\n###.#").unwrap();
+        assert_eq!(map.open_neighbours(1, 1, 0), vec![(1, 0), (1, 2), (2, 1), (1, 1)]);
+        assert_eq!(map.open_neighbours(3, 1, 0), vec![(3, 2), (2, 1), (3, 1)]);
+        assert_eq!(map.open_neighbours(1, 3, 0), vec![(1, 2), (2, 3), (1, 3)]);
+        assert_eq!(map.open_neighbours(3, 3, 0), vec![(3, 2), (3, 4), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn neighbours_middle() {
+        let map = Map::parse("#.###\n#...#\n#...#\n#...#\n###.#").unwrap();
+        assert_eq!(map.open_neighbours(2, 2, 0), vec![(2, 1), (2, 3), (1, 2), (3, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn neighbours_by_blizzards() {
+        let map = Map::parse("#.###\n#.v.#\n#>.<#\n#.^.#\n###.#").unwrap();
+        assert_eq!(map.open_neighbours(2, 2, 0), vec![(2, 2)]);
+        assert_eq!(map.open_neighbours(2, 2, 1), vec![(2, 1), (2, 3), (1, 2), (3, 2)]);
+    }
+
+    #[test]
+    fn time_for_example_route() {
+        let map = Map::parse("#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#").unwrap();
+        let expected = find_time_at_reaching((1, 0), (map.width - 2, map.height - 2), 0, &map) + 1;
+        assert_eq!(expected, 18);
+    }
+
+    #[test]
+    fn time_to_go_back_example() {
+        let map = Map::parse("#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#").unwrap();
+        let expected = find_time_at_reaching((map.width - 2, map.height - 1), (1, 1), 18, &map) + 1;
+        assert_eq!(expected, 18 + 23);
+    }
+
+    fn assert_not_open(map: &Map, not_opens: &[(usize, usize)], time: usize) {
+        for x in 1..(map.width-1) {
+            for y in 1..(map.height-1) {
+                let expect_open = !not_opens.contains(&(x, y));
+                assert_eq!(map.is_open(x, y, time), expect_open, "({:?}, {:?}) @ {} is_open should be {:?}", x, y, time, expect_open);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_maps_without_an_inside_or_gaps() {
+        for (input, line, column, expected) in [
+            ("", 1, 1, "at least 3 rows"),
+            ("#.#\n#.#\n", 3, 1, "at least 3 rows"),
+            ("#.\n#.\n..\n", 1, 1, "a row at least 3 wide"),
+            ("###\n#.#\n#.#\n", 1, 2, "\".\" for the entrance"),
+            ("#.###\n#...#\n#.###\n", 3, 4, "\".\" for the exit"),
+        ] {
+            let error = Map::parse(input).map(|_| ()).unwrap_err();
+            assert_eq!((error.line, error.column, error.expected.as_str()), (line, column, expected), "{:?}", input);
+        }
+        assert_eq!(time_to_exit(&Map::parse("#.#\n#.#\n#.#\n").unwrap()), 2);
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(time_to_exit(&Map::parse(EXAMPLE).unwrap()), 18);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(time_to_exit_return_and_exit_again(&Map::parse(EXAMPLE).unwrap()), 54);
+    }
+}
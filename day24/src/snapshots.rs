@@ -0,0 +1,234 @@
+use std::collections::{HashSet, VecDeque};
+use aoc_common::{Direction, Grid, ParseError};
+
+#[derive(Clone)]
+pub struct Blizzards(Grid<Vec<Direction>>);
+
+impl Blizzards {
+    pub fn new(width: usize, height: usize) -> Blizzards {
+        Blizzards(Grid::new(width, height, vec![]))
+    }
+
+    pub fn next(&self) -> Blizzards {
+        let height = self.height();
+        let width = self.width();
+        let mut next_blizzards = Self::new(width, height);
+
+        for x in 0..width {
+            for y in 0..height {
+                for b in &self.0[(x, y)] {
+                    let (new_x, new_y) = match b {
+                        Direction::North => if y == 1 { (x, height - 2) } else { (x, y - 1) },
+                        Direction::South => if y == height - 2 { (x, 1) } else { (x, y + 1) },
+                        Direction::West => if x == 1 { (width - 2, y) } else { (x - 1, y) },
+                        Direction::East => if x == width - 2 { (1, y) } else { (x + 1, y) },
+                    };
+                    next_blizzards.0[(new_x, new_y)].push(*b);
+                }
+            }
+        }
+
+        next_blizzards
+    }
+
+    pub fn is_open(&self, x: usize, y: usize) -> bool {
+        self.0[(x, y)].is_empty()
+    }
+
+    pub fn draw(&self, walls: &HashSet<(usize, usize)>) {
+        let height = self.height();
+        let width = self.width();
+        for y in 0..height {
+            for x in 0..width {
+                if walls.contains(&(x, y)) {
+                    print!("#");
+                    continue;
+                }
+                let bs = &self.0[(x, y)];
+                if bs.is_empty() {
+                    print!(".");
+                } else if bs.len() == 1 {
+                    match &bs[0] {
+                        Direction::North => print!("^"),
+                        Direction::East => print!(">"),
+                        Direction::South => print!("v"),
+                        Direction::West => print!("<"),
+                    }
+                } else {
+                    print!("{}", bs.len());
+                }
+            }
+            println!();
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.height()
+    }
+    pub fn width(&self) -> usize {
+        self.0.width()
+    }
+}
+
+pub fn time_to_move_between(
+    start_coord: &(usize, usize),
+    end_coord: &(usize, usize),
+    all_blizzards: &[Blizzards],
+    walls: &HashSet<(usize, usize)>,
+    height: usize,
+    start_min: usize,
+) -> usize {
+    let move_deltas = [(0, -1), (0, 1), (-1, 0), (1, 0), (0, 0)];
+
+    // Queue stores elf coord at end of given minute
+    let mut queue = VecDeque::new();
+    queue.push_back((*start_coord, start_min));
+    let mut visited = HashSet::new();
+    while let Some((coord, minute)) = queue.pop_front() {
+        let next_blizzards = &all_blizzards[(minute + 1) % all_blizzards.len()];
+        for delta in &move_deltas {
+            let new_x = coord.0 as isize + delta.0;
+            let new_y = coord.1 as isize + delta.1;
+            if new_y < 0 || new_y >= height as isize {
+                // Can't walk out of the map
+                continue;
+            }
+            let next_coord = (new_x as usize, new_y as usize);
+            if walls.contains(&next_coord) {
+                // Can't walk into walls
+                continue;
+            }
+            if visited.contains(&(next_coord, minute + 1)) {
+                continue;
+            }
+            visited.insert((next_coord, minute + 1));
+            if next_blizzards.is_open(next_coord.0, next_coord.1) {
+                if &next_coord == end_coord {
+                    return minute + 1;
+                } else {
+                    queue.push_back((next_coord, minute + 1));
+                }
+            }
+        }
+    }
+    panic!("Could not find route");
+}
+
+pub struct Valley {
+    pub entrance_coord: (usize, usize),
+    pub exit_coord: (usize, usize),
+    pub width: usize,
+    pub height: usize,
+    pub initial_blizzards: Blizzards,
+    pub walls: HashSet<(usize, usize)>,
+}
+
+impl Valley {
+    pub fn parse(input: &str) -> Result<Valley, ParseError> {
+        use Direction::*;
+        let cells = Grid::try_parse(input, |c| {
+            match c {
+                '.' | '#' | '^' | '>' | 'v' | '<' => Ok(c),
+                _ => Err("one of . # ^ > v <")
+            }
+        })?;
+        crate::check_valley(input, &cells, |&c| c == '.')?;
+
+        let (width, height) = (cells.width(), cells.height());
+        let mut initial_blizzards = Blizzards::new(width, height);
+        let mut walls = HashSet::new();
+        for (coord, &c) in cells.iter() {
+            let (x, y) = (coord.x as usize, coord.y as usize);
+            match c {
+                '#' => { walls.insert((x, y)); },
+                '^' => initial_blizzards.0[(x, y)].push(North),
+                '>' => initial_blizzards.0[(x, y)].push(East),
+                'v' => initial_blizzards.0[(x, y)].push(South),
+                '<' => initial_blizzards.0[(x, y)].push(West),
+                _ => {},
+            }
+        }
+        let entrance_coord = (1, 0);
+        let exit_coord = (width - 2, height - 1);
+        Ok(Valley { entrance_coord, exit_coord, width, height, initial_blizzards, walls })
+    }
+
+    // Precalculate blizzard states. They repeat after a number of minutes dividing the area inside
+    // the walls, so that many states covers every minute.
+    pub fn all_blizzards(&self) -> Vec<Blizzards> {
+        // self.initial_blizzards.draw(&self.walls);
+        let num_states = (self.width - 2) * (self.height - 2);
+        let mut all_blizzards = Vec::with_capacity(num_states);
+        all_blizzards.push(self.initial_blizzards.clone());
+        for i in 1..num_states {
+            let new_blizzards = all_blizzards[i-1].next();
+            // new_blizzards.draw(&self.walls);
+            all_blizzards.push(new_blizzards);
+        }
+        all_blizzards
+    }
+
+    pub fn time_to_move_between(&self, start_coord: &(usize, usize), end_coord: &(usize, usize), all_blizzards: &[Blizzards], start_min: usize) -> usize {
+        time_to_move_between(
+            start_coord,
+            end_coord,
+            all_blizzards,
+            &self.walls,
+            self.height,
+            start_min,
+        )
+    }
+
+    pub fn time_to_exit(&self, all_blizzards: &[Blizzards]) -> usize {
+        self.time_to_move_between(&self.entrance_coord, &self.exit_coord, all_blizzards, 0)
+    }
+
+    pub fn time_to_exit_return_and_exit_again(&self, all_blizzards: &[Blizzards]) -> usize {
+        let first_exit = self.time_to_exit(all_blizzards);
+        let go_back = self.time_to_move_between(&self.exit_coord, &self.entrance_coord, all_blizzards, first_exit);
+        self.time_to_move_between(&self.entrance_coord, &self.exit_coord, all_blizzards, go_back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Valley;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_part1() {
+        let valley = Valley::parse(EXAMPLE).unwrap();
+        assert_eq!(valley.time_to_exit(&valley.all_blizzards()), 18);
+    }
+
+    #[test]
+    fn example_part2() {
+        let valley = Valley::parse(EXAMPLE).unwrap();
+        assert_eq!(valley.time_to_exit_return_and_exit_again(&valley.all_blizzards()), 54);
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_missing_gaps() {
+        let error = Valley::parse("#.###\n#...#\n#....#\n###.#\n").map(|_| ()).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        let error = Valley::parse("#.###\n#...#\n#####\n").map(|_| ()).unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (3, 4, "\".\" for the exit"));
+        assert!(Valley::parse("").is_err());
+    }
+
+    #[test]
+    fn moves_into_the_next_minutes_blizzards() {
+        // Stepping into a blizzard's square just as it leaves is safe, but just as it arrives isn't
+        let valley = Valley::parse("#.###\n#...#\n#>..#\n#>.<#\n###.#\n").unwrap();
+        assert_eq!(valley.time_to_exit(&valley.all_blizzards()), 8);
+    }
+
+    #[test]
+    fn blizzards_repeat_with_the_inside_of_the_valley() {
+        // 6 by 3 inside the walls, so the blizzards repeat every 6 minutes, which doesn't divide the
+        // 40 squares of the whole valley
+        let valley = Valley::parse("#.######\n#..v^<.#\n#..^.^.#\n#..v<^.#\n######.#\n").unwrap();
+        assert_eq!(valley.time_to_exit_return_and_exit_again(&valley.all_blizzards()), 48);
+    }
+}
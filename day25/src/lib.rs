@@ -0,0 +1,96 @@
+use aoc_common::ParseError;
+
+fn snafu_char_to_decimal(snafu: &u8) -> isize {
+    match snafu {
+        b'2' => 2,
+        b'1' => 1,
+        b'0' => 0,
+        b'-' => -1,
+        b'=' => -2,
+        _ => panic!("Unexpected snafu digit {}", snafu),
+    }
+}
+
+pub fn snafu_to_decimal(snafu: &str) -> isize {
+    let mut result = 0isize;
+    let mut col_val = 1;
+    for c in snafu.as_bytes().iter().rev() {
+        result += col_val * snafu_char_to_decimal(c);
+        col_val *= 5;
+    }
+    result
+
+}
+
+/*
+Encoding in a standard base is a simple case of div and mod. SNAFU is a *balanced* quinary system,
+however, where digits can represent a negative value in a column. This complicates things slightly,
+because encoding a single value between 0 and 4 (inclusive) can involve two columns:
+  0 => 00
+  1 => 01
+  2 => 02
+  3 => 1=
+  4 => 1-
+This means that, as we encode each digit, we need to track whether or not we're "carrying" one from
+encoding the digit in the column to the right.
+ */
+pub fn decimal_to_snafu(decimal: isize) -> String {
+    let mut snafu_digits = vec![];
+
+    let mut num = decimal;
+    let mut carry = 0;
+
+    while num > 0 || carry > 0 {
+        let digit_plus_carry = num % 5 + carry;
+        num /= 5;
+        let c = match digit_plus_carry % 5 {
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            3 => '=',
+            4 => '-',
+            _ => unreachable!()
+        };
+        snafu_digits.push(c);
+        carry = if digit_plus_carry > 2 { 1 } else { 0 };
+    }
+
+    snafu_digits.iter().rev().collect()
+}
+
+// Checks each line is a SNAFU number, without converting them yet
+pub fn parse_snafu_numbers(input: &str) -> Result<Vec<&str>, ParseError> {
+    input.lines()
+        .map(|line| match line.char_indices().find(|&(_, c)| !"210-=".contains(c)) {
+            Some((i, c)) => Err(ParseError::at(input, &line[i..i + c.len_utf8()], "a SNAFU digit (2, 1, 0, - or =)")),
+            None => Ok(line),
+        })
+        .collect()
+}
+
+pub fn sum_of_snafu_numbers(snafu_numbers: &[&str]) -> isize {
+    snafu_numbers.iter().map(|snafu| snafu_to_decimal(snafu)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn ints_to_snafu_and_back() {
+        for i in 1..100 {
+            let snafu = decimal_to_snafu(i);
+            let decimal = snafu_to_decimal(&snafu);
+            assert_eq!(i, decimal, "{} -> {} -> {}", i, snafu, decimal);
+        }
+    }
+
+    #[test]
+    fn example_part1() {
+        let sum = sum_of_snafu_numbers(&parse_snafu_numbers(EXAMPLE).unwrap());
+        assert_eq!(sum, 4890);
+        assert_eq!(decimal_to_snafu(sum), "2=-1=0");
+    }
+}